@@ -1,7 +1,11 @@
 use proc_macro_error::abort;
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
-use syn::{FnArg, Ident, ItemEnum, ItemFn, LitStr, ReturnType, Stmt, Type, parse_str, parse2};
+use syn::{
+    FnArg, Generics, Ident, ItemEnum, ItemFn, Lifetime, LitStr, ReturnType, Stmt, Type,
+    parse_quote, parse_str, parse2,
+    visit_mut::{self, VisitMut},
+};
 
 pub fn problem_day_core(args: TokenStream, input: TokenStream) -> TokenStream {
     if !args.is_empty() {
@@ -92,7 +96,16 @@ pub fn problem_parse_core(args: TokenStream, input: TokenStream) -> TokenStream
         Err(e) => return e.to_compile_error(),
     };
 
-    let target = match &mut run.sig.output {
+    let lifetime = match run.sig.generics.lifetimes().collect::<Vec<_>>().as_slice() {
+        [lifetime_param] if run.sig.generics.params.len() == 1 => lifetime_param.lifetime.clone(),
+        _ => abort!(
+            run.sig.generics.to_token_stream(),
+            "Expected exactly 1 lifetime parameter for the input"
+        ),
+    };
+    run.sig.generics = Generics::default();
+
+    let (parses_bytes, target) = match &mut run.sig.output {
         ReturnType::Type(_, t) => match t.as_mut() {
             syn::Type::ImplTrait(type_impl_trait) => {
                 if type_impl_trait.bounds.len() != 1 {
@@ -128,12 +141,18 @@ pub fn problem_parse_core(args: TokenStream, input: TokenStream) -> TokenStream
                                     )
                                 }
 
+                                let parses_bytes = matches!(
+                                    angle_bracketed_generic_arguments.args.get(1),
+                                    Some(syn::GenericArgument::Type(Type::Reference(input)))
+                                        if matches!(*input.elem, Type::Slice(_))
+                                );
+
                                 match angle_bracketed_generic_arguments
                                     .args
                                     .get_mut(2)
                                     .expect("Bounds Checked")
                                 {
-                                    syn::GenericArgument::Type(target) => target,
+                                    syn::GenericArgument::Type(target) => (parses_bytes, target),
                                     _ => abort!(
                                         angle_bracketed_generic_arguments.args.to_token_stream(),
                                         "Unexpected argument, expected type but found something else"
@@ -163,10 +182,34 @@ pub fn problem_parse_core(args: TokenStream, input: TokenStream) -> TokenStream
     let day = target.clone();
     *target = parse2::<Type>(quote! { Self }).expect("Works");
 
+    let mut static_day = day.clone();
+    StaticLifetime(&lifetime).visit_type_mut(&mut static_day);
+
+    let (parse_trait, input_trait) = if parses_bytes {
+        (quote! { ByteParse }, quote! { ByteInput })
+    } else {
+        (quote! { StringParse }, quote! { StringInput })
+    };
+
     quote! {
-        impl StringParse for #day {
+        impl<#lifetime> #parse_trait<#lifetime> for #day {
             #run
         }
+
+        impl #input_trait for #static_day {
+            type Borrowed<#lifetime> = #day;
+        }
+    }
+}
+
+struct StaticLifetime<'l>(&'l Lifetime);
+
+impl VisitMut for StaticLifetime<'_> {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if lifetime == self.0 {
+            *lifetime = Lifetime::new("'static", lifetime.span());
+        }
+        visit_mut::visit_lifetime_mut(self, lifetime);
     }
 }
 
@@ -180,7 +223,35 @@ fn implements_string_parse() {
     let after = problem_parse_core(quote!(), before);
     assert_eq!(
         after.to_string(),
-        "impl StringParse for Day26 { fn parse < 'a > () -> impl Parser < 'a , & 'a str , Self , extra :: Err < Rich < 'a , char > > > { just (\"\") . to (Day26) } }"
+        "impl < 'a > StringParse < 'a > for Day26 { fn parse () -> impl Parser < 'a , & 'a str , Self , extra :: Err < Rich < 'a , char > > > { just (\"\") . to (Day26) } } impl StringInput for Day26 { type Borrowed < 'a > = Day26 ; }"
+    );
+}
+
+#[test]
+fn implements_byte_parse() {
+    let before = quote! {
+        fn parse<'a>() -> impl Parser<'a, &'a [u8], Day26, extra::Err<Rich<'a, u8>>> {
+            just(b"").to(Day26)
+        }
+    };
+    let after = problem_parse_core(quote!(), before);
+    assert_eq!(
+        after.to_string(),
+        "impl < 'a > ByteParse < 'a > for Day26 { fn parse () -> impl Parser < 'a , & 'a [u8] , Self , extra :: Err < Rich < 'a , u8 > > > { just (b\"\") . to (Day26) } } impl ByteInput for Day26 { type Borrowed < 'a > = Day26 ; }"
+    );
+}
+
+#[test]
+fn implements_borrowed_string_parse() {
+    let before = quote! {
+        fn parse<'a>() -> impl Parser<'a, &'a str, Day26<'a>, extra::Err<Rich<'a, char>>> {
+            parse_alphanumeric().map(Day26)
+        }
+    };
+    let after = problem_parse_core(quote!(), before);
+    assert_eq!(
+        after.to_string(),
+        "impl < 'a > StringParse < 'a > for Day26 < 'a > { fn parse () -> impl Parser < 'a , & 'a str , Self , extra :: Err < Rich < 'a , char > > > { parse_alphanumeric () . map (Day26) } } impl StringInput for Day26 < 'static > { type Borrowed < 'a > = Day26 < 'a > ; }"
     );
 }
 
@@ -191,7 +262,10 @@ pub fn enum_parse_core(item: TokenStream) -> TokenStream {
     };
 
     let name = t.ident;
-    let (impl_generics, ty_generics, where_clause) = t.generics.split_for_impl();
+    let mut generics = t.generics.clone();
+    generics.params.insert(0, parse_quote! { 'a });
+    let (impl_generics, _, _) = generics.split_for_impl();
+    let (_, ty_generics, where_clause) = t.generics.split_for_impl();
     let implementation_data = t
         .variants
         .iter()
@@ -235,8 +309,8 @@ pub fn enum_parse_core(item: TokenStream) -> TokenStream {
     };
 
     quote! {
-        impl #impl_generics StringParse for #name #ty_generics #where_clause {
-            fn parse<'a>() -> impl Parser<'a, &'a str, Self, extra::Err<Rich<'a, char>>> {
+        impl #impl_generics StringParse<'a> for #name #ty_generics #where_clause {
+            fn parse() -> impl Parser<'a, &'a str, Self, extra::Err<Rich<'a, char>>> {
                 #(#assignments)*
                 choice((#(#choices),*))
             }
@@ -259,6 +333,6 @@ fn adds_enum_parse_function() {
     let after = enum_parse_core(before);
     assert_eq!(
         after.to_string(),
-        "impl StringParse for Foo { fn parse < 'a > () -> impl Parser < 'a , & 'a str , Self , extra :: Err < Rich < 'a , char >> > { let bar = just (\"b\") . to (Self :: Bar) ; let baz = just (\"az\") . to (Self :: Baz) ; let qux = just (\"q\") . to (Self :: Qux) ; choice ((bar , baz , qux)) } }"
+        "impl < 'a > StringParse < 'a > for Foo { fn parse () -> impl Parser < 'a , & 'a str , Self , extra :: Err < Rich < 'a , char >> > { let bar = just (\"b\") . to (Self :: Bar) ; let baz = just (\"az\") . to (Self :: Baz) ; let qux = just (\"q\") . to (Self :: Qux) ; choice ((bar , baz , qux)) } }"
     );
 }
@@ -0,0 +1,100 @@
+use std::{io::stdout, path::PathBuf};
+
+use anyhow::Result;
+use clap::{value_parser, ArgMatches, Args, Command};
+use clap_complete::{generate, Shell};
+use clap_mangen::Man;
+
+use crate::libs::cli::CliArgs;
+
+// Completions and man pages use the binary's name rather than the app's display name
+const BIN_NAME: &str = env!("CARGO_PKG_NAME");
+
+#[derive(Args)]
+struct CompletionsArguments {
+    #[arg(
+        value_parser = value_parser!(Shell),
+        help = "The shell to generate completions for"
+    )]
+    shell: Shell,
+}
+
+pub fn command() -> Command {
+    CompletionsArguments::augment_args(Command::new("completions"))
+        .about("Prints a shell completion script covering every day, part and argument")
+        .arg_required_else_help(true)
+}
+
+pub fn run(args: &ArgMatches, mut app: Command) -> Result<()> {
+    let arguments = CompletionsArguments::parse_output(args);
+    generate(arguments.shell, &mut app, BIN_NAME, &mut stdout());
+    Ok(())
+}
+
+#[derive(Args)]
+struct ManpageArguments {
+    #[arg(
+        long,
+        value_parser = value_parser!(PathBuf),
+        help = "Write a page for every subcommand to this directory instead of printing the main page"
+    )]
+    out_dir: Option<PathBuf>,
+}
+
+pub fn manpage_command() -> Command {
+    ManpageArguments::augment_args(Command::new("manpage"))
+        .about("Prints the man page, or writes one per subcommand with --out-dir")
+}
+
+pub fn manpage(args: &ArgMatches, app: Command) -> Result<()> {
+    let arguments = ManpageArguments::parse_output(args);
+    let app = app.name(BIN_NAME).bin_name(BIN_NAME);
+
+    match arguments.out_dir {
+        Some(out_dir) => {
+            std::fs::create_dir_all(&out_dir)?;
+            clap_mangen::generate_to(app, &out_dir)?;
+            println!("Wrote man pages to {}", out_dir.display());
+            Ok(())
+        }
+        None => Man::new(app).render(&mut stdout()).map_err(|e| e.into()),
+    }
+}
+
+#[cfg(all(test, feature = "all-days"))]
+fn test_app() -> Command {
+    Command::new("Advent of Code 2024")
+        .subcommand(command())
+        .subcommands(
+            crate::libs::cli::registered_days()
+                .into_iter()
+                .map(|day| day.get_subcommand()),
+        )
+}
+
+#[test]
+#[cfg(feature = "all-days")]
+fn completes_days_parts_and_values() {
+    let mut script = Vec::new();
+    generate(Shell::Bash, &mut test_app(), BIN_NAME, &mut script);
+    let script = String::from_utf8(script).expect("Script is utf8");
+
+    assert!(script.contains("day14"));
+    assert!(script.contains("part2"));
+    assert!(script.contains("--x_size"));
+    assert!(script.contains("fix-adder"));
+    assert!(script.contains("completions"));
+}
+
+#[test]
+#[cfg(feature = "all-days")]
+fn renders_man_page() {
+    let mut page = Vec::new();
+    Man::new(test_app().name(BIN_NAME))
+        .render(&mut page)
+        .expect("Renders");
+    let page = String::from_utf8(page).expect("Page is utf8");
+
+    assert!(page.contains(".TH adventofcode2024"));
+    assert!(page.contains("day24"));
+}
@@ -1,6 +1,7 @@
 use crate::libs::{
-    cli::{new_cli_problem, CliProblem, Freeze},
-    parse::{parse_lines, parse_usize, ParserExt, StringParse},
+    byte_parse::{parse_lines, parse_usize, ByteInput, ByteParse, Bytes},
+    cli::{new_cli_problem, AsCommand, CliProblem, Freeze, RegisteredDay},
+    parse::ParserExt,
     problem::Problem,
 };
 use adventofcode_macro::{problem_day, problem_parse};
@@ -9,7 +10,7 @@ use chumsky::{error::Rich, extra, prelude::just, Parser};
 use clap::{Args, ValueEnum};
 use std::sync::LazyLock;
 
-pub static DAY_01: LazyLock<CliProblem<Day01, CommandLineArguments, Freeze>> =
+pub static DAY_01: LazyLock<CliProblem<Bytes<Day01>, CommandLineArguments, Freeze>> =
     LazyLock::new(|| {
         new_cli_problem(
             "day01",
@@ -33,13 +34,17 @@ pub static DAY_01: LazyLock<CliProblem<Day01, CommandLineArguments, Freeze>> =
         .freeze()
     });
 
+inventory::submit! {
+    RegisteredDay(|| DAY_01.as_command())
+}
+
 #[derive(ValueEnum, Clone)]
 enum ListInterpretation {
     Difference,
     Similarity,
 }
 
-#[derive(Args)]
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     #[arg(short, long, help = "The interpretation of the lists")]
     interpretation: ListInterpretation,
@@ -48,8 +53,8 @@ pub struct CommandLineArguments {
 pub struct Day01(Vec<(usize, usize)>);
 
 #[problem_parse]
-fn parse<'a>() -> impl Parser<'a, &'a str, Day01, extra::Err<Rich<'a, char>>> {
-    parse_lines(parse_usize().then_ignore(just("   ")).then(parse_usize()))
+fn parse<'a>() -> impl Parser<'a, &'a [u8], Day01, extra::Err<Rich<'a, u8>>> {
+    parse_lines(parse_usize().then_ignore(just(b"   ")).then(parse_usize()))
         .map(Day01)
         .end()
 }
@@ -1,16 +1,16 @@
 use crate::libs::{
-    cli::{new_cli_problem, CliProblem, Freeze},
-    parse::{parse_lines, parse_usize, ParserExt, StringParse},
-    problem::Problem,
+    cli::{new_cli_problem, AsCommand, CliProblem, Freeze, RegisteredDay},
+    parse::{parse_usize, StringInput, StringParse},
+    stream::{LineProblem, Lines},
 };
-use adventofcode_macro::{problem_day, problem_parse};
+use adventofcode_macro::problem_parse;
 use ahash::AHashSet;
 use chumsky::{error::Rich, extra, prelude::just, IterParser, Parser};
 use clap::Args;
 use itertools::Itertools;
 use std::sync::LazyLock;
 
-pub static DAY_02: LazyLock<CliProblem<Day02, CommandLineArguments, Freeze>> =
+pub static DAY_02: LazyLock<CliProblem<Lines<Report>, CommandLineArguments, Freeze>> =
     LazyLock::new(|| {
         new_cli_problem(
             "day02",
@@ -34,35 +34,57 @@ pub static DAY_02: LazyLock<CliProblem<Day02, CommandLineArguments, Freeze>> =
         .freeze()
     });
 
-#[derive(Args)]
+inventory::submit! {
+    RegisteredDay(|| DAY_02.as_command())
+}
+
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     #[arg(short, long, help = "Whether to apply error correction to the report")]
     error_correction: bool,
 }
 
-pub struct Day02(Vec<Vec<usize>>);
+pub struct Report(Vec<usize>);
 
 #[problem_parse]
-fn parse<'a>() -> impl Parser<'a, &'a str, Day02, extra::Err<Rich<'a, char>>> {
-    parse_lines(parse_usize().separated_by(just(" ")).at_least(1).collect())
-        .map(Day02)
-        .end()
+fn parse<'a>() -> impl Parser<'a, &'a str, Report, extra::Err<Rich<'a, char>>> {
+    parse_usize()
+        .separated_by(just(" "))
+        .at_least(1)
+        .collect()
+        .map(Report)
 }
 
-#[problem_day]
-fn run(Day02(input): Day02, arguments: &CommandLineArguments) -> usize {
-    let (valid, potentially_invalid): (Vec<_>, Vec<_>) = input
-        .into_iter()
-        .partition(|report| validate_report(report));
-
-    if arguments.error_correction {
-        valid.len()
-            + potentially_invalid
-                .into_iter()
-                .filter(|report| validate_report_with_error(report))
-                .count()
-    } else {
-        valid.len()
+impl LineProblem<CommandLineArguments> for Report {
+    type Accumulator = usize;
+    type Output = usize;
+
+    const PARALLEL: bool = true;
+
+    fn start(_arguments: &CommandLineArguments) -> usize {
+        0
+    }
+
+    fn fold(
+        safe_reports: usize,
+        Report(report): Report,
+        arguments: &CommandLineArguments,
+    ) -> usize {
+        if validate_report(&report)
+            || (arguments.error_correction && validate_report_with_error(&report))
+        {
+            safe_reports + 1
+        } else {
+            safe_reports
+        }
+    }
+
+    fn reduce(left: usize, right: usize, _arguments: &CommandLineArguments) -> usize {
+        left + right
+    }
+
+    fn finish(safe_reports: usize, _arguments: &CommandLineArguments) -> usize {
+        safe_reports
     }
 }
 
@@ -1,6 +1,6 @@
 use crate::libs::{
-    cli::{new_cli_problem, CliProblem, Freeze},
-    parse::{parse_usize, StringParse},
+    cli::{new_cli_problem, AsCommand, CliProblem, Freeze, RegisteredDay},
+    parse::{parse_usize, StringInput, StringParse},
     problem::Problem,
 };
 use adventofcode_macro::{problem_day, problem_parse};
@@ -34,9 +34,13 @@ pub static DAY_03: LazyLock<CliProblem<Day03, CommandLineArguments, Freeze>> = L
     },
 );
 
-#[derive(Args)]
+inventory::submit! {
+    RegisteredDay(|| DAY_03.as_command())
+}
+
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
-    #[arg(short, long, help = "Use the full instruction set or not")]
+    #[arg(short = 'i', long, help = "Use the full instruction set or not")]
     full_instruction_set: bool,
 }
 
@@ -1,7 +1,7 @@
 use crate::libs::{
-    cli::{new_cli_problem, CliProblem, Freeze},
+    cli::{new_cli_problem, AsCommand, CliProblem, Freeze, RegisteredDay},
     graph::{PlanarCoordinate, PointDirection, RADIAL_DIRECTIONS},
-    parse::{parse_table2, ParserExt, StringParse},
+    parse::{parse_table2, ParserExt, StringInput, StringParse},
     problem::Problem,
 };
 use adventofcode_macro::{problem_day, problem_parse};
@@ -35,13 +35,17 @@ pub static DAY_04: LazyLock<CliProblem<Day04, CommandLineArguments, Freeze>> =
         .freeze()
     });
 
+inventory::submit! {
+    RegisteredDay(|| DAY_04.as_command())
+}
+
 #[derive(ValueEnum, Clone)]
 enum SearchSetting {
     Xmas,
     MasInX,
 }
 
-#[derive(Args)]
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     search_setting: SearchSetting,
 }
@@ -1,11 +1,11 @@
 use crate::libs::{
-    cli::{new_cli_problem, CliProblem, Freeze},
-    parse::{parse_lines, parse_usize, ParserExt, StringParse},
+    cli::{new_cli_problem, AsCommand, CliProblem, Freeze, RegisteredDay},
+    parse::{parse_comma_separated, parse_lines, parse_usize, ParserExt, StringInput, StringParse},
     problem::Problem,
 };
 use adventofcode_macro::{problem_day, problem_parse};
 use ahash::{AHashMap, AHashSet};
-use chumsky::{error::Rich, extra, prelude::just, text, IterParser, Parser};
+use chumsky::{error::Rich, extra, prelude::just, text, Parser};
 use clap::Args;
 use std::sync::LazyLock;
 
@@ -29,7 +29,11 @@ pub static DAY_05: LazyLock<CliProblem<Day05, CommandLineArguments, Freeze>> =
         .freeze()
     });
 
-#[derive(Args)]
+inventory::submit! {
+    RegisteredDay(|| DAY_05.as_command())
+}
+
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     #[arg(short, long, help = "If the updates should be valid or not")]
     valid: bool,
@@ -44,7 +48,7 @@ pub struct Day05 {
 #[problem_parse]
 fn parse<'a>() -> impl Parser<'a, &'a str, Day05, extra::Err<Rich<'a, char>>> {
     let page_rules = parse_lines(parse_usize().then_ignore(just("|")).then(parse_usize()));
-    let page_updates = parse_lines(parse_usize().separated_by(just(",")).at_least(1).collect());
+    let page_updates = parse_lines(parse_comma_separated(parse_usize()));
 
     page_rules
         .then_ignore(text::newline().repeated().at_least(1))
@@ -1,11 +1,15 @@
-use crate::libs::{
-    cli::{new_cli_problem, CliProblem, Freeze},
-    graph::{
-        breadth_first_search, BreadthFirstSearchLifecycle, CardinalDirection, Direction,
-        PlanarCoordinate, CARDINAL_DIRECTIONS,
+use crate::{
+    count,
+    libs::{
+        cli::{new_cli_problem, AsCommand, CliProblem, Freeze, RegisteredDay},
+        graph::{
+            breadth_first_search, BreadthFirstSearchLifecycle, CardinalDirection, Direction,
+            PlanarCoordinate, CARDINAL_DIRECTIONS,
+        },
+        parse::{parse_table2, ParserExt, StringInput, StringParse},
+        problem::Problem,
     },
-    parse::{parse_table2, ParserExt, StringParse},
-    problem::Problem,
+    trace_phase,
 };
 use adventofcode_macro::{problem_day, problem_parse, StringParse};
 use chumsky::{
@@ -43,13 +47,17 @@ pub static DAY_06: LazyLock<CliProblem<Day06, CommandLineArguments, Freeze>> =
         .freeze()
     });
 
+inventory::submit! {
+    RegisteredDay(|| DAY_06.as_command())
+}
+
 #[derive(ValueEnum, Clone)]
 enum AvoidenceStrategy {
     FullPath,
     Loop,
 }
 
-#[derive(Args)]
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     #[arg(short, long, help = "The interpretation of the lists")]
     avoidence_strategy: AvoidenceStrategy,
@@ -81,27 +89,35 @@ fn run(Day06(input): Day06, arguments: &CommandLineArguments) -> usize {
         .expect("Guard exists");
     let guard_facing = CardinalDirection::Up;
 
-    let guard_path = run(guard_position, guard_facing, &input)
-        .map(|visited| {
-            visited
-                .fold_axis(Axis(2), false, |acc, value| *acc || *value)
-                .indexed_iter()
-                .filter(|(_, value)| **value)
-                .map(|(index, _)| index)
-                .collect::<Vec<_>>()
-        })
-        .expect("Result exists");
+    let guard_path = {
+        trace_phase!("guard_path");
+        run(guard_position, guard_facing, &input)
+            .map(|visited| {
+                visited
+                    .fold_axis(Axis(2), false, |acc, value| *acc || *value)
+                    .indexed_iter()
+                    .filter(|(_, value)| **value)
+                    .map(|(index, _)| index)
+                    .collect::<Vec<_>>()
+            })
+            .expect("Result exists")
+    };
 
     match arguments.avoidence_strategy {
         AvoidenceStrategy::FullPath => guard_path.len(),
         AvoidenceStrategy::Loop => {
-            let mut sparse_lab = build_obstruction_mapping(&input);
+            let mut sparse_lab = {
+                trace_phase!("build_obstruction_mapping");
+                build_obstruction_mapping(&input)
+            };
+            trace_phase!("loop_detection");
             let mut visited = Array3::from_elem((input.dim().0, input.dim().1, 4), 0);
             guard_path
                 .into_iter()
                 .filter(|point| *point != guard_position)
                 .enumerate()
                 .filter(|(index, obstruction)| {
+                    count!("obstructions_tried", 1);
                     let old = add_obstruction(*obstruction, &mut sparse_lab);
                     let result = does_guard_loop(
                         guard_position,
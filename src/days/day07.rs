@@ -1,15 +1,15 @@
 use crate::libs::{
-    cli::{CliProblem, Freeze, new_cli_problem},
-    parse::{ParserExt, StringParse, parse_lines, parse_usize},
-    problem::Problem,
+    cli::{AsCommand, CliProblem, Freeze, RegisteredDay, new_cli_problem},
+    parse::{StringInput, StringParse, parse_usize},
+    stream::{LineProblem, Lines},
 };
-use adventofcode_macro::{problem_day, problem_parse};
+use adventofcode_macro::problem_parse;
 use chumsky::{IterParser, Parser, error::Rich, extra, prelude::just};
 use clap::{Args, ValueEnum};
 use std::sync::LazyLock;
 
-pub static DAY_07: LazyLock<CliProblem<Day07, CommandLineArguments, Freeze>> = LazyLock::new(
-    || {
+pub static DAY_07: LazyLock<CliProblem<Lines<TestInput>, CommandLineArguments, Freeze>> =
+    LazyLock::new(|| {
         new_cli_problem(
             "day07",
             "Interprets different lists of ids",
@@ -30,10 +30,13 @@ pub static DAY_07: LazyLock<CliProblem<Day07, CommandLineArguments, Freeze>> = L
             vec![("sample.txt", 11387)],
         )
         .freeze()
-    },
-);
+    });
 
-#[derive(Args)]
+inventory::submit! {
+    RegisteredDay(|| DAY_07.as_command())
+}
+
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     #[arg(short, long, num_args = 1..3, value_delimiter = ' ', required = true, help = "The list of operators to test")]
     operators: Vec<Operator>,
@@ -46,40 +49,52 @@ enum Operator {
     Concat,
 }
 
-struct TestInput {
+pub struct TestInput {
     result: usize,
     test_values: Vec<usize>,
 }
 
-pub struct Day07(Vec<TestInput>);
-
 #[problem_parse]
-fn parse<'a>() -> impl Parser<'a, &'a str, Day07, extra::Err<Rich<'a, char>>> {
+fn parse<'a>() -> impl Parser<'a, &'a str, TestInput, extra::Err<Rich<'a, char>>> {
     let test_values = parse_usize().separated_by(just(" ")).at_least(1).collect();
-    let test_input =
-        parse_usize()
-            .then_ignore(just(": "))
-            .then(test_values)
-            .map(|(result, test_values)| TestInput {
-                result,
-                test_values,
-            });
-    parse_lines(test_input).map(Day07).end()
+    parse_usize()
+        .then_ignore(just(": "))
+        .then(test_values)
+        .map(|(result, test_values)| TestInput {
+            result,
+            test_values,
+        })
 }
 
-#[problem_day]
-fn run(Day07(input): Day07, arguments: &CommandLineArguments) -> usize {
-    input
-        .into_iter()
-        .filter(|test_input| {
-            can_satisfy(
-                &test_input.test_values,
-                test_input.result,
-                &arguments.operators,
-            )
-        })
-        .map(|test_input| test_input.result)
-        .sum()
+impl LineProblem<CommandLineArguments> for TestInput {
+    type Accumulator = usize;
+    type Output = usize;
+
+    const PARALLEL: bool = true;
+
+    fn start(_arguments: &CommandLineArguments) -> usize {
+        0
+    }
+
+    fn fold(total: usize, test_input: TestInput, arguments: &CommandLineArguments) -> usize {
+        if can_satisfy(
+            &test_input.test_values,
+            test_input.result,
+            &arguments.operators,
+        ) {
+            total + test_input.result
+        } else {
+            total
+        }
+    }
+
+    fn reduce(left: usize, right: usize, _arguments: &CommandLineArguments) -> usize {
+        left + right
+    }
+
+    fn finish(total: usize, _arguments: &CommandLineArguments) -> usize {
+        total
+    }
 }
 
 fn can_satisfy(test_values: &[usize], target: usize, operators: &[Operator]) -> bool {
@@ -1,7 +1,7 @@
 use crate::libs::{
-    cli::{new_cli_problem, CliProblem, Freeze},
+    cli::{new_cli_problem, AsCommand, CliProblem, Freeze, RegisteredDay},
     graph::{Direction, PlanarCoordinate},
-    parse::{parse_table2, ParserExt, StringParse},
+    parse::{parse_table2, ParserExt, StringInput, StringParse},
     problem::Problem,
 };
 use adventofcode_macro::{problem_day, problem_parse};
@@ -41,13 +41,17 @@ pub static DAY_08: LazyLock<CliProblem<Day08, CommandLineArguments, Freeze>> =
         .freeze()
     });
 
+inventory::submit! {
+    RegisteredDay(|| DAY_08.as_command())
+}
+
 #[derive(ValueEnum, Clone)]
 enum Resonance {
     Discrete,
     Harmonic,
 }
 
-#[derive(Args)]
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     #[arg(
         short,
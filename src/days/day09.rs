@@ -1,6 +1,6 @@
 use crate::libs::{
-    cli::{CliProblem, Freeze, new_cli_problem},
-    parse::{StringParse, parse_digit},
+    cli::{AsCommand, CliProblem, Freeze, RegisteredDay, new_cli_problem},
+    parse::{StringInput, StringParse, parse_digit},
     problem::Problem,
 };
 use adventofcode_macro::{problem_day, problem_parse};
@@ -39,13 +39,17 @@ pub static DAY_09: LazyLock<CliProblem<Day09, CommandLineArguments, Freeze>> =
         .freeze()
     });
 
+inventory::submit! {
+    RegisteredDay(|| DAY_09.as_command())
+}
+
 #[derive(ValueEnum, Clone)]
 enum CompressionStrategy {
     HighestCompression,
     FirstAvailableSlot,
 }
 
-#[derive(Args)]
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     #[arg(short, long, help = "Strategy to reformat the memory")]
     compression_strategy: CompressionStrategy,
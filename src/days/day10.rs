@@ -1,7 +1,8 @@
 use crate::libs::{
-    cli::{new_cli_problem, CliProblem, Freeze},
+    byte_parse::{parse_digit, parse_table2, ByteInput, ByteParse, Bytes},
+    cli::{new_cli_problem, AsCommand, CliProblem, Freeze, RegisteredDay},
     graph::{breadth_first_search, BreadthFirstSearchLifecycle, PlanarCoordinate},
-    parse::{parse_digit, parse_table2, ParserExt, StringParse},
+    parse::ParserExt,
     problem::Problem,
 };
 use adventofcode_macro::{problem_day, problem_parse};
@@ -12,7 +13,7 @@ use either::Either;
 use ndarray::Array2;
 use std::{collections::VecDeque, iter, sync::LazyLock};
 
-pub static DAY_10: LazyLock<CliProblem<Day10, CommandLineArguments, Freeze>> =
+pub static DAY_10: LazyLock<CliProblem<Bytes<Day10>, CommandLineArguments, Freeze>> =
     LazyLock::new(|| {
         new_cli_problem(
             "day10",
@@ -36,13 +37,17 @@ pub static DAY_10: LazyLock<CliProblem<Day10, CommandLineArguments, Freeze>> =
         .freeze()
     });
 
+inventory::submit! {
+    RegisteredDay(|| DAY_10.as_command())
+}
+
 #[derive(ValueEnum, Clone)]
 enum ScoringSystem {
     UniquePeaks,
     UniquePaths,
 }
 
-#[derive(Args)]
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     #[arg(short, long, help = "Way to score a trail head")]
     scoring: ScoringSystem,
@@ -51,10 +56,8 @@ pub struct CommandLineArguments {
 pub struct Day10(Array2<u32>);
 
 #[problem_parse]
-fn parse<'a>() -> impl Parser<'a, &'a str, Day10, extra::Err<Rich<'a, char>>> {
-    parse_table2(parse_digit().map(|c| c.to_digit(10).expect("Works")))
-        .map(Day10)
-        .end()
+fn parse<'a>() -> impl Parser<'a, &'a [u8], Day10, extra::Err<Rich<'a, u8>>> {
+    parse_table2(parse_digit().map(u32::from)).map(Day10).end()
 }
 
 #[problem_day]
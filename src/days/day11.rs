@@ -1,11 +1,11 @@
 use crate::libs::{
-    cli::{new_cli_problem, CliProblem, Freeze},
-    parse::{parse_usize, ParserExt, StringParse},
+    cli::{new_cli_problem, AsCommand, CliProblem, Freeze, RegisteredDay},
+    parse::{parse_space_separated, parse_usize, ParserExt, StringInput, StringParse},
     problem::Problem,
 };
 use adventofcode_macro::{problem_day, problem_parse};
 use ahash::AHashMap;
-use chumsky::{error::Rich, extra, prelude::just, IterParser, Parser};
+use chumsky::{error::Rich, extra, Parser};
 use clap::Args;
 use std::sync::LazyLock;
 
@@ -29,7 +29,11 @@ pub static DAY_11: LazyLock<CliProblem<Day11, CommandLineArguments, Freeze>> =
         .freeze()
     });
 
-#[derive(Args)]
+inventory::submit! {
+    RegisteredDay(|| DAY_11.as_command())
+}
+
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     #[arg(short, long, help = "Number of times to blink")]
     n: usize,
@@ -39,12 +43,7 @@ pub struct Day11(Vec<usize>);
 
 #[problem_parse]
 fn parse<'a>() -> impl Parser<'a, &'a str, Day11, extra::Err<Rich<'a, char>>> {
-    parse_usize()
-        .separated_by(just(" "))
-        .at_least(1)
-        .collect()
-        .map(Day11)
-        .end()
+    parse_space_separated(parse_usize()).map(Day11).end()
 }
 
 #[problem_day]
@@ -1,10 +1,10 @@
 use crate::libs::{
-    cli::{new_cli_problem, CliProblem, Freeze},
+    cli::{new_cli_problem, AsCommand, CliProblem, Freeze, RegisteredDay},
     graph::{
         breadth_first_search, BreadthFirstSearchLifecycle, PlanarCoordinate, PointDirection,
         CARDINAL_DIRECTIONS,
     },
-    parse::{parse_table2, ParserExt, StringParse},
+    parse::{parse_table2, ParserExt, StringInput, StringParse},
     problem::Problem,
 };
 use adventofcode_macro::{problem_day, problem_parse};
@@ -46,15 +46,19 @@ pub static DAY_12: LazyLock<CliProblem<Day12, CommandLineArguments, Freeze>> =
         .freeze()
     });
 
+inventory::submit! {
+    RegisteredDay(|| DAY_12.as_command())
+}
+
 #[derive(ValueEnum, Clone)]
 enum FenceScore {
     Perimeter,
     Fences,
 }
 
-#[derive(Args)]
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
-    #[arg(short, long, help = "How to score the fence prices for a region")]
+    #[arg(short = 's', long, help = "How to score the fence prices for a region")]
     fence_score: FenceScore,
 }
 
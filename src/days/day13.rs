@@ -1,6 +1,6 @@
 use crate::libs::{
-    cli::{new_cli_problem, CliProblem, Freeze},
-    parse::{parse_between_blank_lines, parse_isize, StringParse},
+    cli::{new_cli_problem, AsCommand, CliProblem, Freeze, RegisteredDay},
+    parse::{parse_between_blank_lines, parse_isize, StringInput, StringParse},
     problem::Problem,
 };
 use adventofcode_macro::{problem_day, problem_parse};
@@ -29,7 +29,11 @@ pub static DAY_13: LazyLock<CliProblem<Day13, CommandLineArguments, Freeze>> = L
     },
 );
 
-#[derive(Args)]
+inventory::submit! {
+    RegisteredDay(|| DAY_13.as_command())
+}
+
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     #[arg(short, long, help = "The offset to the prize's position")]
     offset: isize,
@@ -1,6 +1,9 @@
 use crate::libs::{
-    cli::{flag_arg, new_cli_problem, single_arg, CliArgs, CliProblem, Freeze},
-    parse::{parse_isize, parse_lines, ParserExt, StringParse},
+    cli::{
+        flag_arg, is_explicit, new_cli_problem, single_arg, AsCommand, CliArgs, CliProblem, Freeze,
+        RegisteredDay,
+    },
+    parse::{parse_isize, parse_lines, ParserExt, StringInput, StringParse},
     problem::Problem,
 };
 use adventofcode_macro::{problem_day, problem_parse};
@@ -40,11 +43,17 @@ pub static DAY_14: LazyLock<CliProblem<Day14, CommandLineArguments, Freeze>> =
         .freeze()
     });
 
+inventory::submit! {
+    RegisteredDay(|| DAY_14.as_command())
+}
+
+#[derive(Clone)]
 enum RobotStat {
     SafetyFactor(usize),
     FindTree(bool),
 }
 
+#[derive(Clone)]
 pub struct CommandLineArguments {
     x_size: usize,
     y_size: usize,
@@ -79,20 +88,41 @@ impl CliArgs for CommandLineArguments {
             .map(|t| RobotStat::SafetyFactor(*t));
 
         let print_tree = args.get_flag("print_tree");
+        // A safety factor from aoc.toml gives way to looking for the tree on the command line
+        let tree_requested = is_explicit(args, "find_tree") || is_explicit(args, "print_tree");
 
         match safety_factor_after {
-            Some(robot_stat) => CommandLineArguments {
+            Some(robot_stat) if !tree_requested => CommandLineArguments {
                 x_size,
                 y_size,
                 robot_stat,
             },
-            None => CommandLineArguments {
+            _ => CommandLineArguments {
                 x_size,
                 y_size,
                 robot_stat: RobotStat::FindTree(print_tree),
             },
         }
     }
+
+    fn update_output(&mut self, args: &clap::ArgMatches) {
+        if let Some(x_size) = args.get_one::<usize>("x_size") {
+            self.x_size = *x_size;
+        }
+        if let Some(y_size) = args.get_one::<usize>("y_size") {
+            self.y_size = *y_size;
+        }
+
+        let print_tree = args.get_one::<bool>("print_tree").is_some_and(|flag| *flag);
+        match (
+            args.get_one::<usize>("safety_factory_after"),
+            args.get_one::<bool>("find_tree").is_some_and(|flag| *flag) || print_tree,
+        ) {
+            (Some(time), _) => self.robot_stat = RobotStat::SafetyFactor(*time),
+            (None, true) => self.robot_stat = RobotStat::FindTree(print_tree),
+            (None, false) => {}
+        }
+    }
 }
 
 #[derive(Debug)]
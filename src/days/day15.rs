@@ -1,7 +1,7 @@
 use crate::libs::{
-    cli::{CliProblem, Freeze, new_cli_problem},
+    cli::{AsCommand, CliProblem, Freeze, RegisteredDay, new_cli_problem},
     graph::{CardinalDirection, Direction, PlanarCoordinate},
-    parse::{ParserExt, StringParse, parse_lines, parse_table2},
+    parse::{ParserExt, StringInput, StringParse, parse_lines, parse_table2},
     problem::Problem,
 };
 use adventofcode_macro::{StringParse, problem_day, problem_parse};
@@ -37,7 +37,11 @@ pub static DAY_15: LazyLock<CliProblem<Day15, CommandLineArguments, Freeze>> =
         .freeze()
     });
 
-#[derive(Args)]
+inventory::submit! {
+    RegisteredDay(|| DAY_15.as_command())
+}
+
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     #[arg(short, long, help = "If the warehouse is wide or not")]
     wide: bool,
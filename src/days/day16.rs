@@ -1,10 +1,7 @@
 use crate::libs::{
-    cli::{CliProblem, Freeze, new_cli_problem},
-    graph::{
-        BoundedPoint, CARDINAL_DIRECTIONS, CardinalDirection, Direction, PlanarCoordinate,
-        dijkstras,
-    },
-    parse::{ParserExt, StringParse, parse_table2},
+    cli::{AsCommand, CliProblem, Freeze, RegisteredDay, new_cli_problem},
+    graph::{CARDINAL_DIRECTIONS, CardinalDirection, Direction, PlanarCoordinate, dijkstras},
+    parse::{MarkedGrid, ParserExt, StringInput, StringParse, parse_grid_with_markers},
     problem::Problem,
 };
 use adventofcode_macro::{StringParse, problem_day, problem_parse};
@@ -44,13 +41,17 @@ pub static DAY_16: LazyLock<CliProblem<Day16, CommandLineArguments, Freeze>> =
         .freeze()
     });
 
+inventory::submit! {
+    RegisteredDay(|| DAY_16.as_command())
+}
+
 #[derive(ValueEnum, Clone)]
 enum PathStat {
     ShortestWeight,
     TotalSeats,
 }
 
-#[derive(Args)]
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     #[arg(short, long, help = "What stat about the maze to calculate")]
     path_stat: PathStat,
@@ -58,44 +59,48 @@ pub struct CommandLineArguments {
 
 #[derive(Debug, Clone, StringParse)]
 enum Maze {
-    #[literal("S")]
-    Start,
-    #[literal("E")]
-    End,
     #[literal(".")]
     Open,
     #[literal("#")]
     Wall,
 }
 
-pub struct Day16(Array2<Maze>);
+#[derive(Debug, Clone, PartialEq)]
+enum Marker {
+    Start,
+    End,
+}
+
+pub struct Day16(MarkedGrid<Maze, Marker>);
 
 #[problem_parse]
 fn parse<'a>() -> impl Parser<'a, &'a str, Day16, extra::Err<Rich<'a, char>>> {
-    parse_table2(Maze::parse()).map(Day16).end()
+    let cell = choice((
+        just('S').to((Maze::Open, Some(Marker::Start))),
+        just('E').to((Maze::Open, Some(Marker::End))),
+        Maze::parse().map(|maze| (maze, None)),
+    ));
+    parse_grid_with_markers(cell).map(Day16).end()
 }
 
 #[problem_day]
 fn run(Day16(input): Day16, arguments: &CommandLineArguments) -> usize {
-    let (max_x, max_y) = BoundedPoint::maxes_from_table(&input);
-
-    let start = input
-        .indexed_iter()
-        .find(|(_, item)| matches!(item, Maze::Start))
-        .map(|(index, _)| BoundedPoint::from_table_index(index, max_x, max_y))
-        .expect("Exists");
+    let start = input.find(&Marker::Start).expect("Exists");
+    let end = input.find(&Marker::End).expect("Exists");
 
     match arguments.path_stat {
         PathStat::ShortestWeight => {
-            find_shortest_path_weight(&(start.y, start.x), &input).expect("Exists")
-        }
-        PathStat::TotalSeats => {
-            find_all_shortest_paths(&(start.y, start.x), &input).expect("Exists")
+            find_shortest_path_weight(&start, &end, &input.grid).expect("Exists")
         }
+        PathStat::TotalSeats => find_all_shortest_paths(&start, &end, &input.grid).expect("Exists"),
     }
 }
 
-fn find_all_shortest_paths(start: &(usize, usize), maze: &Array2<Maze>) -> Option<usize> {
+fn find_all_shortest_paths(
+    start: &(usize, usize),
+    end: &(usize, usize),
+    maze: &Array2<Maze>,
+) -> Option<usize> {
     let mut queue = PriorityQueue::new();
     queue.push((*start, CardinalDirection::Right), Reverse(0));
 
@@ -112,11 +117,7 @@ fn find_all_shortest_paths(start: &(usize, usize), maze: &Array2<Maze>) -> Optio
         queue,
         &mut visited,
         |_| None,
-        |((point, _), cost)| {
-            maze.get(*point)
-                .filter(|maze_type| matches!(maze_type, Maze::End))
-                .map(|_| (*cost, *point))
-        },
+        |((point, _), cost)| (point == end).then_some((*cost, *point)),
         |((point, direction), _)| get_valid_moves(direction, point, maze),
         |((point, direction), _), ((new_point, new_direction), new_cost)| {
             let path = visited_path
@@ -166,7 +167,11 @@ fn find_all_shortest_paths(start: &(usize, usize), maze: &Array2<Maze>) -> Optio
     })
 }
 
-fn find_shortest_path_weight(start: &(usize, usize), maze: &Array2<Maze>) -> Option<usize> {
+fn find_shortest_path_weight(
+    start: &(usize, usize),
+    end: &(usize, usize),
+    maze: &Array2<Maze>,
+) -> Option<usize> {
     let mut queue = PriorityQueue::new();
     queue.push((*start, CardinalDirection::Right), Reverse(0));
 
@@ -176,11 +181,7 @@ fn find_shortest_path_weight(start: &(usize, usize), maze: &Array2<Maze>) -> Opt
         queue,
         &mut visited,
         |_| None,
-        |((point, _), cost)| {
-            maze.get(*point)
-                .filter(|maze_type| matches!(maze_type, Maze::End))
-                .map(|_| *cost)
-        },
+        |((point, _), cost)| (point == end).then_some(*cost),
         |((point, direction), _)| get_valid_moves(direction, point, maze),
         |_, _| (),
     )
@@ -195,7 +196,7 @@ fn get_valid_moves(
         .get_adjacent(*direction)
         .filter(|point| {
             maze.get(*point)
-                .is_some_and(|location| matches!(location, Maze::Open | Maze::End | Maze::Start))
+                .is_some_and(|location| matches!(location, Maze::Open))
         })
         .map(|point| ((point, *direction), 1))
         .into_iter()
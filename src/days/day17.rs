@@ -1,6 +1,9 @@
 use crate::libs::{
-    cli::{new_cli_problem, CliProblem, Freeze},
-    parse::{parse_usize, ParserExt, StringParse},
+    cli::{new_cli_problem, AsCommand, CliProblem, Freeze, RegisteredDay},
+    parse::{
+        parse_comma_separated, parse_key_value, parse_key_values, parse_usize, ParserExt,
+        StringInput, StringParse,
+    },
     problem::{Problem, ProblemResult},
 };
 use adventofcode_macro::{problem_day, problem_parse, StringParse};
@@ -8,8 +11,8 @@ use ahash::AHashMap;
 use chumsky::{
     error::Rich,
     extra,
-    prelude::{choice, just},
-    text, IterParser, Parser,
+    prelude::{choice, just, one_of},
+    text, Parser,
 };
 use clap::{Args, ValueEnum};
 use itertools::Itertools;
@@ -40,13 +43,17 @@ pub static DAY_17: LazyLock<CliProblem<Day17, CommandLineArguments, Freeze>> =
         .freeze()
     });
 
+inventory::submit! {
+    RegisteredDay(|| DAY_17.as_command())
+}
+
 #[derive(ValueEnum, Clone)]
 enum ProgramExecution {
     Run,
     FindQuine,
 }
 
-#[derive(Args)]
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     #[arg(short, long, help = "What to do with the input program.")]
     program_execution: ProgramExecution,
@@ -97,21 +104,16 @@ pub struct Day17 {
 
 #[problem_parse]
 fn parse<'a>() -> impl Parser<'a, &'a str, Day17, extra::Err<Rich<'a, char>>> {
-    let program = parse_instruction()
-        .separated_by(just(","))
-        .at_least(1)
-        .collect::<Vec<_>>();
-
-    just("Register A: ")
-        .ignore_then(parse_usize())
-        .then_ignore(text::newline())
-        .then_ignore(just("Register B: "))
-        .then(parse_usize())
-        .then_ignore(text::newline())
-        .then_ignore(just("Register C: "))
-        .then(parse_usize())
+    let registers = parse_key_values(just("Register ").ignore_then(one_of("ABC")), parse_usize())
+        .try_map(|registers, span| match registers[..] {
+            [('A', a), ('B', b), ('C', c)] => Ok(((a, b), c)),
+            _ => Err(Rich::custom(span, "Expected registers A, B and C")),
+        });
+    let program = parse_key_value(just("Program"), parse_comma_separated(parse_instruction()))
+        .map(|(_, program)| program);
+
+    registers
         .then_ignore(text::newline().repeated().at_least(1))
-        .then_ignore(just("Program: "))
         .then(program)
         .map(|(((a, b), c), program)| Day17 { a, b, c, program })
         .end()
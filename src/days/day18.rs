@@ -1,11 +1,14 @@
 use crate::libs::{
-    cli::{CliArgs, CliProblem, Freeze, flag_arg, new_cli_problem, single_arg},
+    cli::{
+        AsCommand, CliArgs, CliProblem, Freeze, RegisteredDay, flag_arg, is_explicit,
+        new_cli_problem, single_arg,
+    },
     graph::{BreadthFirstSearchLifecycle, PlanarCoordinate, breadth_first_search},
-    parse::{ParserExt, StringParse, parse_lines, parse_usize},
+    parse::{ParserExt, StringInput, StringParse, parse_coordinate, parse_integer, parse_lines},
     problem::{Problem, ProblemResult},
 };
 use adventofcode_macro::{problem_day, problem_parse};
-use chumsky::{Parser, error::Rich, extra, prelude::just};
+use chumsky::{Parser, error::Rich, extra};
 use clap::value_parser;
 use ndarray::Array2;
 use std::{collections::VecDeque, sync::LazyLock};
@@ -38,11 +41,17 @@ pub static DAY_18: LazyLock<CliProblem<Day18, CommandLineArguments, Freeze>> =
         .freeze()
     });
 
+inventory::submit! {
+    RegisteredDay(|| DAY_18.as_command())
+}
+
+#[derive(Clone)]
 enum PathStat {
     ShortestPath(usize),
     FirstBlockage,
 }
 
+#[derive(Clone)]
 pub struct CommandLineArguments {
     x_size: usize,
     y_size: usize,
@@ -82,18 +91,34 @@ impl CliArgs for CommandLineArguments {
 
         let blockage = args.get_flag("blockage");
 
-        match (shortest_path, blockage) {
-            (None, true) => CommandLineArguments {
-                x_size,
-                y_size,
-                path_stat: PathStat::FirstBlockage,
-            },
-            (Some(path_stat), false) => CommandLineArguments {
-                x_size,
-                y_size,
-                path_stat,
-            },
-            _ => unreachable!(),
+        // The two conflict on the command line, so both are only set when aoc.toml gives a
+        // default, which the one given on the command line wins over
+        let path_stat = match (shortest_path, blockage) {
+            (Some(path_stat), true) if !is_explicit(args, "blockage") => path_stat,
+            (_, true) => PathStat::FirstBlockage,
+            (Some(path_stat), false) => path_stat,
+            (None, false) => unreachable!(),
+        };
+
+        CommandLineArguments {
+            x_size,
+            y_size,
+            path_stat,
+        }
+    }
+
+    fn update_output(&mut self, args: &clap::ArgMatches) {
+        if let Some(x_size) = args.get_one::<usize>("x_size") {
+            self.x_size = *x_size;
+        }
+        if let Some(y_size) = args.get_one::<usize>("y_size") {
+            self.y_size = *y_size;
+        }
+
+        if let Some(corruptions) = args.get_one::<usize>("shortest_path") {
+            self.path_stat = PathStat::ShortestPath(*corruptions);
+        } else if args.get_one::<bool>("blockage").is_some_and(|flag| *flag) {
+            self.path_stat = PathStat::FirstBlockage;
         }
     }
 }
@@ -102,7 +127,7 @@ pub struct Day18(Vec<(usize, usize)>);
 
 #[problem_parse]
 fn parse<'a>() -> impl Parser<'a, &'a str, Day18, extra::Err<Rich<'a, char>>> {
-    parse_lines(parse_usize().then_ignore(just(",")).then(parse_usize()))
+    parse_lines(parse_coordinate(parse_integer()))
         .map(Day18)
         .end()
 }
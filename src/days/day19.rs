@@ -1,6 +1,6 @@
 use crate::libs::{
-    cli::{new_cli_problem, CliProblem, Freeze},
-    parse::{parse_lines, ParserExt, StringParse},
+    cli::{new_cli_problem, AsCommand, CliProblem, Freeze, RegisteredDay},
+    parse::{parse_lines, ParserExt, StringInput, StringParse},
     problem::Problem,
 };
 use adventofcode_macro::{problem_day, problem_parse, StringParse};
@@ -39,13 +39,17 @@ pub static DAY_19: LazyLock<CliProblem<Day19, CommandLineArguments, Freeze>> = L
     },
 );
 
+inventory::submit! {
+    RegisteredDay(|| DAY_19.as_command())
+}
+
 #[derive(ValueEnum, Clone)]
 enum TowelOptions {
     TowelPossible,
     NumberOfWaysPossible,
 }
 
-#[derive(Args)]
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     #[arg(
         short,
@@ -1,10 +1,10 @@
 use crate::libs::{
-    cli::{new_cli_problem, CliProblem, Freeze},
+    cli::{new_cli_problem, AsCommand, CliProblem, Freeze, RegisteredDay},
     graph::{
         breadth_first_search, BreadthFirstSearchLifecycle, CardinalDirection, HorizontalDirection,
         PlanarCoordinate, VerticalDirection, CARDINAL_DIRECTIONS,
     },
-    parse::{parse_table2, ParserExt, StringParse},
+    parse::{parse_table2, ParserExt, StringInput, StringParse},
     problem::Problem,
 };
 use adventofcode_macro::{problem_day, problem_parse, StringParse};
@@ -47,7 +47,11 @@ pub static DAY_20: LazyLock<CliProblem<Day20, CommandLineArguments, Freeze>> =
         .freeze()
     });
 
-#[derive(Args)]
+inventory::submit! {
+    RegisteredDay(|| DAY_20.as_command())
+}
+
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     #[arg(
         short,
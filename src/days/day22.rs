@@ -1,15 +1,19 @@
-use crate::libs::{
-    cli::{new_cli_problem, CliProblem, Freeze},
-    parse::{parse_lines, parse_usize, ParserExt, StringParse},
-    problem::Problem,
+use crate::{
+    count,
+    libs::{
+        cli::{new_cli_problem, AsCommand, CliProblem, Freeze, RegisteredDay},
+        parse::{parse_usize, StringInput, StringParse},
+        stream::{LineProblem, Lines},
+    },
+    trace_phase,
 };
-use adventofcode_macro::{problem_day, problem_parse};
+use adventofcode_macro::problem_parse;
 use chumsky::{error::Rich, extra, Parser};
 use clap::{Args, ValueEnum};
 use itertools::{iterate, Itertools};
-use std::sync::LazyLock;
+use std::{sync::LazyLock, time::Duration};
 
-pub static DAY_22: LazyLock<CliProblem<Day22, CommandLineArguments, Freeze>> =
+pub static DAY_22: LazyLock<CliProblem<Lines<Monkey>, CommandLineArguments, Freeze>> =
     LazyLock::new(|| {
         new_cli_problem(
             "day22",
@@ -30,16 +34,21 @@ pub static DAY_22: LazyLock<CliProblem<Day22, CommandLineArguments, Freeze>> =
             },
             vec![("sample2.txt", 23)],
         )
+        .with_budget(Duration::from_millis(40))
         .freeze()
     });
 
+inventory::submit! {
+    RegisteredDay(|| DAY_22.as_command())
+}
+
 #[derive(ValueEnum, Clone)]
 enum BananaMarketInformation {
     LastSecret,
     MostBananas,
 }
 
-#[derive(Args)]
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     #[arg(
         short,
@@ -49,34 +58,118 @@ pub struct CommandLineArguments {
     banana_market_information: BananaMarketInformation,
 }
 
-pub struct Day22(Vec<usize>);
+pub struct Monkey(usize);
 
 #[problem_parse]
-fn parse<'a>() -> impl Parser<'a, &'a str, Day22, extra::Err<Rich<'a, char>>> {
-    parse_lines(parse_usize()).map(Day22).end()
+fn parse<'a>() -> impl Parser<'a, &'a str, Monkey, extra::Err<Rich<'a, char>>> {
+    parse_usize().map(Monkey)
 }
 
-#[problem_day]
-fn run(Day22(input): Day22, arguments: &CommandLineArguments) -> usize {
-    match arguments.banana_market_information {
-        BananaMarketInformation::LastSecret => input
-            .into_iter()
-            .flat_map(|number| iterate(number, |number| next_secret(*number)).nth(2000))
-            .sum(),
-        BananaMarketInformation::MostBananas => input
-            .into_iter()
-            .enumerate()
-            .fold(
-                (vec![0_u16; 1_048_576], vec![0_u16; 1_048_576]),
-                |mut acc, (index, number)| {
-                    price_by_last_four_deltas(number, (index + 1) as u16, &mut acc.0, &mut acc.1);
-                    acc
+pub enum MarketTotals {
+    LastSecrets(usize),
+    Prices {
+        monkeys: u16,
+        prices: Vec<u16>,
+        seen: Vec<u16>,
+    },
+}
+
+impl LineProblem<CommandLineArguments> for Monkey {
+    type Accumulator = MarketTotals;
+    type Output = usize;
+
+    // Every split of a parallel fold would allocate its own price tables
+    const PARALLEL: bool = false;
+
+    fn start(arguments: &CommandLineArguments) -> MarketTotals {
+        match arguments.banana_market_information {
+            BananaMarketInformation::LastSecret => MarketTotals::LastSecrets(0),
+            BananaMarketInformation::MostBananas => MarketTotals::Prices {
+                monkeys: 0,
+                prices: vec![0_u16; 1_048_576],
+                seen: vec![0_u16; 1_048_576],
+            },
+        }
+    }
+
+    fn fold(
+        totals: MarketTotals,
+        Monkey(number): Monkey,
+        _arguments: &CommandLineArguments,
+    ) -> MarketTotals {
+        match totals {
+            MarketTotals::LastSecrets(sum) => MarketTotals::LastSecrets(
+                sum + iterate(number, |number| next_secret(*number))
+                    .nth(2000)
+                    .unwrap_or(0),
+            ),
+            MarketTotals::Prices {
+                monkeys,
+                mut prices,
+                mut seen,
+            } => {
+                count!("secrets_generated", 2000);
+                // 0 marks a key no monkey has seen, so the markers restart when the count wraps
+                let monkeys = match monkeys.wrapping_add(1) {
+                    0 => {
+                        seen.fill(0);
+                        1
+                    }
+                    monkeys => monkeys,
+                };
+                price_by_last_four_deltas(number, monkeys, &mut prices, &mut seen);
+                MarketTotals::Prices {
+                    monkeys,
+                    prices,
+                    seen,
+                }
+            }
+        }
+    }
+
+    fn reduce(
+        left: MarketTotals,
+        right: MarketTotals,
+        _arguments: &CommandLineArguments,
+    ) -> MarketTotals {
+        match (left, right) {
+            (MarketTotals::LastSecrets(left), MarketTotals::LastSecrets(right)) => {
+                MarketTotals::LastSecrets(left + right)
+            }
+            // The left markers stay valid, since the next generation folded into them is newer
+            (
+                MarketTotals::Prices {
+                    monkeys,
+                    mut prices,
+                    seen,
+                },
+                MarketTotals::Prices {
+                    prices: right_prices,
+                    ..
                 },
-            )
-            .0
-            .into_iter()
-            .max()
-            .unwrap_or(0) as usize,
+            ) => {
+                prices
+                    .iter_mut()
+                    .zip(right_prices)
+                    .for_each(|(price, right_price)| *price += right_price);
+                MarketTotals::Prices {
+                    monkeys,
+                    prices,
+                    seen,
+                }
+            }
+            _ => unreachable!("Totals always start from the same arguments"),
+        }
+    }
+
+    fn finish(totals: MarketTotals, _arguments: &CommandLineArguments) -> usize {
+        match totals {
+            MarketTotals::LastSecrets(sum) => sum,
+            MarketTotals::Prices { prices, .. } => {
+                trace_phase!("find_best_price");
+                prices.into_iter().max().unwrap_or(0) as usize
+            }
+        }
     }
 }
 
@@ -1,6 +1,6 @@
 use crate::libs::{
-    cli::{new_cli_problem, CliProblem, Freeze},
-    parse::{parse_lines, ParserExt, StringParse},
+    cli::{new_cli_problem, AsCommand, CliProblem, Freeze, RegisteredDay},
+    parse::{parse_lines, ParserExt, StringInput, StringParse},
     problem::{Problem, ProblemResult},
 };
 use adventofcode_macro::{problem_day, problem_parse};
@@ -15,7 +15,7 @@ use clap::{Args, ValueEnum};
 use itertools::Itertools;
 use std::sync::LazyLock;
 
-pub static DAY_23: LazyLock<CliProblem<Day23, CommandLineArguments, Freeze>> =
+pub static DAY_23: LazyLock<CliProblem<Day23<'static>, CommandLineArguments, Freeze>> =
     LazyLock::new(|| {
         new_cli_problem(
             "day23",
@@ -39,27 +39,27 @@ pub static DAY_23: LazyLock<CliProblem<Day23, CommandLineArguments, Freeze>> =
         .freeze()
     });
 
+inventory::submit! {
+    RegisteredDay(|| DAY_23.as_command())
+}
+
 #[derive(ValueEnum, Clone)]
 enum ConnectionInformation {
     MutualTruplesWithT,
     MostMutualConnections,
 }
 
-#[derive(Args)]
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     #[arg(short, long, help = "The type of connection information desired.")]
     connection_information: ConnectionInformation,
 }
 
-pub struct Day23(Vec<(String, String)>);
+pub struct Day23<'a>(Vec<(&'a str, &'a str)>);
 
 #[problem_parse]
-fn parse<'a>() -> impl Parser<'a, &'a str, Day23, extra::Err<Rich<'a, char>>> {
-    let alpha = one_of('a'..='z');
-    let computer = alpha
-        .clone()
-        .then(alpha)
-        .map(|(a, b)| format!("{}{}", a, b));
+fn parse<'a>() -> impl Parser<'a, &'a str, Day23<'a>, extra::Err<Rich<'a, char>>> {
+    let computer = one_of('a'..='z').repeated().exactly(2).to_slice();
 
     parse_lines(computer.clone().then_ignore(just("-")).then(computer))
         .map(Day23)
@@ -67,12 +67,12 @@ fn parse<'a>() -> impl Parser<'a, &'a str, Day23, extra::Err<Rich<'a, char>>> {
 }
 
 #[problem_day]
-fn run(Day23(input): Day23, arguments: &CommandLineArguments) -> ProblemResult {
+fn run(Day23(input): Day23<'_>, arguments: &CommandLineArguments) -> ProblemResult {
     let computer_to_connections = input.into_iter().fold(
         AHashMap::new(),
-        |mut acc: AHashMap<String, AHashSet<String>>, (a, b)| {
-            let a_entry = acc.entry(a.clone()).or_default();
-            a_entry.insert(b.clone());
+        |mut acc: AHashMap<&str, AHashSet<&str>>, (a, b)| {
+            let a_entry = acc.entry(a).or_default();
+            a_entry.insert(b);
             let b_entry = acc.entry(b).or_default();
             b_entry.insert(a);
             acc
@@ -114,7 +114,7 @@ fn run(Day23(input): Day23, arguments: &CommandLineArguments) -> ProblemResult {
         }
         ConnectionInformation::MostMutualConnections => get_most_mutual_connections(
             AHashSet::new(),
-            computer_to_connections.keys().map(|s| s.as_str()).collect(),
+            computer_to_connections.keys().copied().collect(),
             AHashSet::new(),
             0,
             &computer_to_connections,
@@ -131,7 +131,7 @@ fn get_most_mutual_connections<'a>(
     candidates: AHashSet<&'a str>,
     mut visited: AHashSet<&'a str>,
     best_found: usize,
-    graph: &'a AHashMap<String, AHashSet<String>>,
+    graph: &AHashMap<&'a str, AHashSet<&'a str>>,
 ) -> AHashSet<&'a str> {
     if in_set.len() + candidates.len() <= best_found {
         return AHashSet::new();
@@ -152,14 +152,7 @@ fn get_most_mutual_connections<'a>(
                 .copied()
                 .collect(),
             candidates
-                .intersection(
-                    &graph
-                        .get(*computer)
-                        .expect("Exists")
-                        .iter()
-                        .map(|s| s.as_str())
-                        .collect::<AHashSet<&str>>(),
-                )
+                .intersection(graph.get(*computer).expect("Exists"))
                 .filter(|computer| !visited.contains(**computer))
                 .copied()
                 .collect(),
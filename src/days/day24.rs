@@ -1,6 +1,6 @@
 use crate::libs::{
-    cli::{CliProblem, Freeze, new_cli_problem},
-    parse::{ParserExt, StringParse, parse_alphanumeric, parse_lines},
+    cli::{AsCommand, CliProblem, Freeze, RegisteredDay, new_cli_problem},
+    parse::{ParserExt, StringInput, StringParse, parse_alphanumeric, parse_lines},
     problem::{Problem, ProblemResult},
 };
 use adventofcode_macro::{StringParse, problem_day, problem_parse};
@@ -16,8 +16,8 @@ use clap::{Args, ValueEnum};
 use itertools::Itertools;
 use std::{collections::VecDeque, sync::LazyLock};
 
-pub static DAY_24: LazyLock<CliProblem<Day24, CommandLineArguments, Freeze>> = LazyLock::new(
-    || {
+pub static DAY_24: LazyLock<CliProblem<Day24<'static>, CommandLineArguments, Freeze>> =
+    LazyLock::new(|| {
         new_cli_problem(
             "day24",
             "Finds information about a circuit",
@@ -41,8 +41,11 @@ pub static DAY_24: LazyLock<CliProblem<Day24, CommandLineArguments, Freeze>> = L
             vec![],
         )
         .freeze()
-    },
-);
+    });
+
+inventory::submit! {
+    RegisteredDay(|| DAY_24.as_command())
+}
 
 #[derive(ValueEnum, Clone)]
 enum WireTask {
@@ -50,17 +53,17 @@ enum WireTask {
     FixAdder,
 }
 
-#[derive(Args)]
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {
     #[arg(short, long, help = "What to do with the wires")]
     wire_task: WireTask,
 }
 
 #[derive(Debug, Clone)]
-struct Gate {
-    operand1: String,
-    operand2: String,
-    result: String,
+struct Gate<'a> {
+    operand1: &'a str,
+    operand2: &'a str,
+    result: &'a str,
     gate_type: GateType,
 }
 
@@ -75,31 +78,27 @@ enum GateType {
 }
 
 #[derive(Debug)]
-pub struct Day24 {
-    gate_values: Vec<(String, bool)>,
-    gates: Vec<Gate>,
+pub struct Day24<'a> {
+    gate_values: Vec<(&'a str, bool)>,
+    gates: Vec<Gate<'a>>,
 }
 
 #[problem_parse]
-fn parse<'a>() -> impl Parser<'a, &'a str, Day24, extra::Err<Rich<'a, char>>> {
+fn parse<'a>() -> impl Parser<'a, &'a str, Day24<'a>, extra::Err<Rich<'a, char>>> {
     let bool = just("1").to(true).or(just("0").to(false));
-    let gate_value = parse_alphanumeric()
-        .map(|s: &'a str| s.to_string())
-        .then_ignore(just(": "))
-        .then(bool);
+    let gate_value = parse_alphanumeric().then_ignore(just(": ")).then(bool);
     let gate_values = gate_value
         .separated_by(text::newline())
         .at_least(1)
         .collect::<Vec<_>>();
 
     let gate = parse_alphanumeric()
-        .map(|s: &'a str| s.to_string())
         .then_ignore(just(" "))
         .then(GateType::parse())
         .then_ignore(just(" "))
-        .then(parse_alphanumeric().map(|s: &'a str| s.to_string()))
+        .then(parse_alphanumeric())
         .then_ignore(just(" -> "))
-        .then(parse_alphanumeric().map(|s: &'a str| s.to_string()))
+        .then(parse_alphanumeric())
         .map(|(((operand1, gate_type), operand2), result)| Gate {
             operand1,
             operand2,
@@ -116,7 +115,7 @@ fn parse<'a>() -> impl Parser<'a, &'a str, Day24, extra::Err<Rich<'a, char>>> {
 }
 
 #[problem_day]
-fn run(Day24 { gate_values, gates }: Day24, arguments: &CommandLineArguments) -> ProblemResult {
+fn run(Day24 { gate_values, gates }: Day24<'_>, arguments: &CommandLineArguments) -> ProblemResult {
     match arguments.wire_task {
         WireTask::Simulate => {
             let mut gate_values = gate_values.into_iter().collect::<AHashMap<_, _>>();
@@ -145,11 +144,11 @@ fn run(Day24 { gate_values, gates }: Day24, arguments: &CommandLineArguments) ->
     }
 }
 
-fn find_addition_carry_and_swaps(
+fn find_addition_carry_and_swaps<'a>(
     i: usize,
-    carry_in_gate: Option<String>,
-    gates: &[Gate],
-) -> (Option<String>, Option<(String, String)>) {
+    carry_in_gate: Option<&'a str>,
+    gates: &[Gate<'a>],
+) -> (Option<&'a str>, Option<(&'a str, &'a str)>) {
     let x_gate = format!("x{:0>2}", i);
     let y_gate = format!("y{:0>2}", i);
     let mut swapped_gates = None;
@@ -161,7 +160,7 @@ fn find_addition_carry_and_swaps(
                 && (gate.operand2 == x_gate || gate.operand2 == y_gate)
                 && gate.gate_type == GateType::Xor
         })
-        .map(|gate| gate.result.clone());
+        .map(|gate| gate.result);
 
     let carry_gate = gates
         .iter()
@@ -170,7 +169,7 @@ fn find_addition_carry_and_swaps(
                 && (gate.operand2 == x_gate || gate.operand2 == y_gate)
                 && gate.gate_type == GateType::And
         })
-        .map(|gate| gate.result.clone());
+        .map(|gate| gate.result);
 
     if carry_in_gate.is_none() {
         return (
@@ -178,7 +177,7 @@ fn find_addition_carry_and_swaps(
                 (Some(add), Some(carry)) => {
                     if !add.starts_with("z") {
                         // Add result detected as wrong, must be swapped with carry
-                        swapped_gates = Some((add.clone(), carry.clone()));
+                        swapped_gates = Some((add, carry));
                         Some(add)
                     } else {
                         Some(carry)
@@ -206,13 +205,13 @@ fn find_addition_carry_and_swaps(
                 && (gate.operand2 == add_gate || gate.operand2 == carry_in_gate)
                 && gate.gate_type == GateType::Xor
         })
-        .map(|gate| gate.result.clone());
+        .map(|gate| gate.result);
 
     let final_add = if let Some(final_add) = final_add {
         final_add
     } else {
         // Final addition detected as wrong, carry and add must be swapped
-        swapped_gates = Some((add_gate.clone(), carry_gate.clone()));
+        swapped_gates = Some((add_gate, carry_gate));
         (add_gate, carry_gate) = (carry_gate, add_gate);
         gates
             .iter()
@@ -221,7 +220,7 @@ fn find_addition_carry_and_swaps(
                     && (gate.operand2 == add_gate || gate.operand2 == carry_in_gate)
                     && gate.gate_type == GateType::Xor
             })
-            .map(|gate| gate.result.clone())
+            .map(|gate| gate.result)
             .expect("Exists after swap")
     };
 
@@ -232,7 +231,7 @@ fn find_addition_carry_and_swaps(
                 && (gate.operand2 == add_gate || gate.operand2 == carry_in_gate)
                 && gate.gate_type == GateType::And
         })
-        .map(|gate| gate.result.clone())
+        .map(|gate| gate.result)
         .expect("Should always exist at this point.");
 
     let final_carry = gates
@@ -242,7 +241,7 @@ fn find_addition_carry_and_swaps(
                 && (gate.operand2 == carry_gate || gate.operand2 == carry_in_add)
                 && gate.gate_type == GateType::Or
         })
-        .map(|gate| gate.result.clone());
+        .map(|gate| gate.result);
 
     (
         if let Some(final_carry) = final_carry {
@@ -250,7 +249,7 @@ fn find_addition_carry_and_swaps(
                 let next_z_gate = format!("z{:0>2}", i + 1);
                 if final_carry != next_z_gate {
                     // final carry detected as wrong, final_add and final_carry must be swapped
-                    swapped_gates = Some((final_add.clone(), final_carry.clone()));
+                    swapped_gates = Some((final_add, final_carry));
                     return (Some(final_add), swapped_gates);
                 }
 
@@ -264,11 +263,11 @@ fn find_addition_carry_and_swaps(
             if !final_add.starts_with("z") {
                 if carry_in_add.starts_with("z") {
                     // carry_in_add, final_add swapped
-                    swapped_gates = Some((final_add.clone(), carry_in_add.clone()));
+                    swapped_gates = Some((final_add, carry_in_add));
                     carry_in_add = final_add;
                 } else if carry_gate.starts_with("z") {
                     // carry_gate, final_add swapped
-                    swapped_gates = Some((final_add.clone(), carry_gate.clone()));
+                    swapped_gates = Some((final_add, carry_gate));
                     carry_gate = final_add;
                 }
             }
@@ -280,13 +279,13 @@ fn find_addition_carry_and_swaps(
                         || (gate.operand2 == carry_gate || gate.operand2 == carry_in_add)
                             && gate.gate_type == GateType::Or
                 })
-                .map(|gate| gate.result.clone())
+                .map(|gate| gate.result)
         },
         swapped_gates,
     )
 }
 
-fn extract_output_gates(gate_values: &AHashMap<String, bool>) -> usize {
+fn extract_output_gates(gate_values: &AHashMap<&str, bool>) -> usize {
     let mut result = 0;
     gate_values
         .iter()
@@ -303,7 +302,7 @@ fn extract_output_gates(gate_values: &AHashMap<String, bool>) -> usize {
     result
 }
 
-fn simulate_gates(gate_values: &mut AHashMap<String, bool>, gates: &[Gate]) {
+fn simulate_gates<'a>(gate_values: &mut AHashMap<&'a str, bool>, gates: &[Gate<'a>]) {
     let mut visited = AHashSet::new();
     let mut gates_to_process = VecDeque::from_iter(gates.iter());
 
@@ -322,8 +321,8 @@ fn simulate_gates(gate_values: &mut AHashMap<String, bool>, gates: &[Gate]) {
                     GateType::Xor => operand1 ^ operand2,
                 };
 
-                gate_values.insert(gate.result.clone(), result);
-                visited.insert(gate.result.clone());
+                gate_values.insert(gate.result, result);
+                visited.insert(gate.result);
             }
             _ => gates_to_process.push_back(gate),
         }
@@ -1,7 +1,7 @@
 use crate::libs::{
-    cli::{new_cli_problem, CliProblem, Freeze},
+    cli::{new_cli_problem, AsCommand, CliProblem, Freeze, RegisteredDay},
     graph::BoundedPoint,
-    parse::{parse_between_blank_lines, parse_table2, StringParse},
+    parse::{parse_between_blank_lines, parse_table2, StringInput, StringParse},
     problem::Problem,
 };
 use adventofcode_macro::{problem_day, problem_parse, StringParse};
@@ -26,7 +26,11 @@ pub static DAY_25: LazyLock<CliProblem<Day25, CommandLineArguments, Freeze>> =
         .freeze()
     });
 
-#[derive(Args)]
+inventory::submit! {
+    RegisteredDay(|| DAY_25.as_command())
+}
+
+#[derive(Args, Clone)]
 pub struct CommandLineArguments {}
 
 #[derive(Debug, Clone, PartialEq, Eq, StringParse)]
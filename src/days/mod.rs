@@ -1,25 +1,50 @@
+#[cfg(feature = "day01")]
 pub(crate) mod day01;
+#[cfg(feature = "day02")]
 pub(crate) mod day02;
+#[cfg(feature = "day03")]
 pub(crate) mod day03;
+#[cfg(feature = "day04")]
 pub(crate) mod day04;
+#[cfg(feature = "day05")]
 pub(crate) mod day05;
+#[cfg(feature = "day06")]
 pub(crate) mod day06;
+#[cfg(feature = "day07")]
 pub(crate) mod day07;
+#[cfg(feature = "day08")]
 pub(crate) mod day08;
+#[cfg(feature = "day09")]
 pub(crate) mod day09;
+#[cfg(feature = "day10")]
 pub(crate) mod day10;
+#[cfg(feature = "day11")]
 pub(crate) mod day11;
+#[cfg(feature = "day12")]
 pub(crate) mod day12;
+#[cfg(feature = "day13")]
 pub(crate) mod day13;
+#[cfg(feature = "day14")]
 pub(crate) mod day14;
+#[cfg(feature = "day15")]
 pub(crate) mod day15;
+#[cfg(feature = "day16")]
 pub(crate) mod day16;
+#[cfg(feature = "day17")]
 pub(crate) mod day17;
+#[cfg(feature = "day18")]
 pub(crate) mod day18;
+#[cfg(feature = "day19")]
 pub(crate) mod day19;
+#[cfg(feature = "day20")]
 pub(crate) mod day20;
+#[cfg(feature = "day21")]
 pub(crate) mod day21;
+#[cfg(feature = "day22")]
 pub(crate) mod day22;
+#[cfg(feature = "day23")]
 pub(crate) mod day23;
+#[cfg(feature = "day24")]
 pub(crate) mod day24;
+#[cfg(feature = "day25")]
 pub(crate) mod day25;
@@ -1,29 +1,182 @@
-use std::{io, path::PathBuf, thread, time::Duration};
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Display},
+    fs::{create_dir_all, read_to_string, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    thread,
+    time::Duration,
+};
 
-use anyhow::{Ok, Result};
-use chrono::{Local, NaiveTime};
-use clap::{ArgMatches, Args, Command};
+use anyhow::{anyhow, Ok, Result};
+use chrono::{DateTime, Local, NaiveTime, TimeZone, Utc};
+use chrono_tz::America::New_York;
+use chumsky::{error::Rich, extra, prelude::one_of, primitive::just, IterParser, Parser};
+use clap::{value_parser, ArgMatches, Args, Command};
 use cookie_store::CookieStore;
-use dialoguer::Confirm;
-use scraper::{Html, Selector};
-use tap::Tap;
+use dialoguer::{Confirm, Password};
+use itertools::Itertools;
+use scraper::{ElementRef, Html, Selector};
 use ureq::{Agent, AgentBuilder, Cookie};
 use url::Url;
 
-use crate::libs::{cli::CliArgs, file_system::save_string_to_file};
+use crate::libs::{
+    cli::{parse_day, CliArgs, PART_NAMES},
+    file_system::save_string_to_file,
+    http_cache::{CachePolicy, HttpCache},
+    markdown::html_to_markdown,
+    parse::parse_usize,
+    problem::ProblemResult,
+    profile::{input_directory, profile, profile_name},
+    sessions::{default_sessions_file, read_session, save_session},
+};
 
 #[derive(Args)]
-struct CommandLineArguments {
-    #[arg(short, long, help = "The day to download the input from")]
-    day: usize,
-
+struct SiteArguments {
     #[arg(
         short,
         long,
         env = "AOC_SESSION",
-        help = "The advent of code session token that can be found in your cookies."
+        help = "The advent of code session token that can be found in your cookies. Defaults to the token saved by login for the profile."
+    )]
+    session: Option<String>,
+
+    #[arg(
+        long,
+        env = "AOC_SESSIONS_FILE",
+        help = "Where login saves session tokens. Defaults to adventofcode2024/sessions in the config directory."
+    )]
+    sessions_file: Option<PathBuf>,
+
+    #[arg(
+        long,
+        env = "AOC_BASE_URL",
+        default_value = "https://adventofcode.com",
+        value_parser = parse_base_url,
+        help = "The base url of the advent of code site."
     )]
-    session: String,
+    base_url: Url,
+
+    #[arg(
+        short,
+        long,
+        env = "AOC_YEAR",
+        default_value_t = YEAR,
+        help = "The event year to use. Files for other years are saved in a directory for the year inside input."
+    )]
+    year: u16,
+
+    #[arg(
+        long,
+        env = "AOC_CACHE_DIR",
+        default_value = ".aoc_cache",
+        help = "Where responses from the site are cached between runs."
+    )]
+    cache_dir: PathBuf,
+
+    #[arg(
+        long,
+        env = "AOC_REQUEST_INTERVAL",
+        default_value_t = 5,
+        help = "The minimum number of seconds between requests to the site, across runs."
+    )]
+    request_interval: u64,
+
+    #[arg(
+        long,
+        env = "AOC_USER_AGENT",
+        default_value = USER_AGENT,
+        help = "The User-Agent header sent with every request."
+    )]
+    user_agent: String,
+}
+
+// The year the solvers are written for, whose files live directly in input
+const YEAR: u16 = 2024;
+
+const USER_AGENT: &str = concat!(
+    "adventofcode2024/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/ktsimpso/adventofcode2024)"
+);
+
+// Urls are joined relative to the base, so it must end with a slash to keep any path
+fn parse_base_url(base_url: &str) -> Result<Url, url::ParseError> {
+    if base_url.ends_with('/') {
+        Url::parse(base_url)
+    } else {
+        Url::parse(&format!("{}/", base_url))
+    }
+}
+
+struct PuzzleSite {
+    agent: Agent,
+    cache: HttpCache,
+    url: Url,
+    year: u16,
+}
+
+impl SiteArguments {
+    fn sessions_file(&self) -> Result<PathBuf> {
+        self.sessions_file
+            .clone()
+            .map(Ok)
+            .unwrap_or_else(default_sessions_file)
+    }
+}
+
+impl PuzzleSite {
+    fn new(arguments: SiteArguments) -> Result<Self> {
+        let session = match &arguments.session {
+            Some(session) => session.clone(),
+            None => {
+                read_session(&arguments.sessions_file()?, profile_name())?.ok_or_else(|| {
+                    anyhow!(
+                        "No session token for profile {}, pass --session or save one with login",
+                        profile_name()
+                    )
+                })?
+            }
+        };
+
+        Self::with_session(arguments, session)
+    }
+
+    fn with_session(arguments: SiteArguments, session: String) -> Result<Self> {
+        let cookie = Cookie::new("session", session);
+        let mut cookie_store = CookieStore::default();
+        cookie_store.insert_raw(&cookie, &arguments.base_url)?;
+
+        Ok(PuzzleSite {
+            agent: AgentBuilder::new()
+                .cookie_store(cookie_store)
+                .user_agent(&arguments.user_agent)
+                .build(),
+            // Inputs share urls between accounts, so each profile needs its own cache
+            cache: HttpCache::new(
+                arguments.cache_dir,
+                profile(),
+                Duration::from_secs(arguments.request_interval),
+            ),
+            url: arguments.base_url,
+            year: arguments.year,
+        })
+    }
+
+    fn day_url(&self, day: usize, path: &str) -> Result<Url> {
+        self.url
+            .join(&format!("{}/day/{}{}", self.year, day, path))
+            .map_err(|e| e.into())
+    }
+}
+
+#[derive(Args)]
+struct CommandLineArguments {
+    #[arg(short, long, help = "The day to download the input from")]
+    day: usize,
+
+    #[command(flatten)]
+    site: SiteArguments,
 
     #[arg(short, long, help = "Always download the input file")]
     force: bool,
@@ -35,27 +188,71 @@ struct CommandLineArguments {
     )]
     download_time: Option<String>,
 
+    #[arg(
+        long,
+        conflicts_with = "download_time",
+        help = "Wait until the puzzle unlocks at midnight US-Eastern, download as soon as the input is available and parse the sample input"
+    )]
+    at_unlock: bool,
+
     #[arg(short, long, help = "Also attempt to parse the sample input")]
     parse_sample: bool,
+
+    #[arg(
+        short,
+        long,
+        help = "Ask which code blocks are samples instead of detecting them automatically"
+    )]
+    interactive: bool,
+
+    #[arg(
+        long,
+        help = "Also save the puzzle description as markdown to input/day{}/puzzle.md"
+    )]
+    description: bool,
 }
 
 pub fn command() -> Command {
     CommandLineArguments::augment_args(Command::new("download_input"))
-        .about("Downloads the input file for a particular problem day and saves it to input/day{}/input.txt")
+        .about("Downloads the input file for a particular problem day and saves it to input/[profile/]day{}/input.txt")
         .arg_required_else_help(true)
         .subcommand_negates_reqs(true)
 }
 
+#[derive(Args)]
+struct SubmitArguments {
+    #[arg(short, long, help = "The day to submit the answer for")]
+    day: usize,
+
+    #[arg(
+        short,
+        long,
+        value_parser = value_parser!(u8).range(1..=2),
+        help = "The part of the day the answer is for"
+    )]
+    part: u8,
+
+    #[arg(
+        short,
+        long,
+        help = "The answer to submit. If not provided the solver is run on the input file and its result is submitted."
+    )]
+    answer: Option<String>,
+
+    #[command(flatten)]
+    site: SiteArguments,
+}
+
+pub fn submit_command() -> Command {
+    SubmitArguments::augment_args(Command::new("submit"))
+        .about("Submits an answer for a particular problem day and part, recording the outcome to input/[profile/]day{}/submissions.txt")
+        .arg_required_else_help(true)
+}
+
 pub fn run(args: &ArgMatches) -> Result<()> {
     let arguments = CommandLineArguments::parse_output(args);
 
-    let url = Url::parse("https://adventofcode.com")?;
-    let cookie = Cookie::build(("session", arguments.session))
-        .domain(url.domain().expect("Domain exists"))
-        .build();
-    let mut cookie_store = CookieStore::default();
-    cookie_store.insert_raw(&cookie, &url)?;
-    let agent = AgentBuilder::new().cookie_store(cookie_store).build();
+    let site = PuzzleSite::new(arguments.site)?;
 
     match arguments.download_time {
         Some(time) => {
@@ -81,18 +278,183 @@ pub fn run(args: &ArgMatches) -> Result<()> {
         None => Ok(()),
     }?;
 
-    fetch_and_save_input_file(&agent, &url, arguments.day, arguments.force)?;
+    if arguments.at_unlock {
+        wait_for_unlock(unlock_time(site.year, arguments.day)?)?;
+        fetch_input_when_available(&site, arguments.day, arguments.force)?;
+    } else {
+        fetch_and_save_input_file(&site, arguments.day, arguments.force)?;
+    }
 
-    if arguments.parse_sample {
-        fetch_and_save_samples(&agent, &url, arguments.day, arguments.force)
+    let parse_sample = arguments.parse_sample || arguments.at_unlock;
+    if !parse_sample && !arguments.description {
+        return Ok(());
+    }
+
+    println!("Downloading the page information");
+    let page = Html::parse_document(&site.cache.get(
+        &site.agent,
+        &site.day_url(arguments.day, "")?,
+        CachePolicy::Revalidate,
+    )?);
+
+    if arguments.description {
+        save_description(&page, site.year, arguments.day)?;
+    }
+
+    if parse_sample {
+        save_samples(
+            &page,
+            site.year,
+            arguments.day,
+            arguments.force,
+            arguments.interactive,
+        )
     } else {
         Ok(())
     }
 }
 
-fn fetch_and_save_input_file(agent: &Agent, url: &Url, day: usize, force: bool) -> Result<()> {
-    let input_file =
-        &PathBuf::new().tap_mut(|path| path.push(format!("input/day{:0>2}/input.txt", day)));
+#[derive(Args)]
+struct LoginArguments {
+    #[command(flatten)]
+    site: SiteArguments,
+}
+
+pub fn login_command() -> Command {
+    LoginArguments::augment_args(Command::new("login"))
+        .about("Checks a session token is logged in and saves it for the profile, prompting for it if --session is not set")
+}
+
+pub fn login(args: &ArgMatches) -> Result<()> {
+    let arguments = LoginArguments::parse_output(args);
+    let sessions_file = arguments.site.sessions_file()?;
+
+    let session = match arguments.site.session.clone() {
+        Some(session) => session,
+        None => Password::new()
+            .with_prompt("Session token from the site's cookies")
+            .interact()?,
+    };
+
+    let site = PuzzleSite::with_session(arguments.site, session.clone())?;
+    site.cache.throttle()?;
+    let page = site
+        .agent
+        .get(site.url.join(&format!("{}/", site.year))?.as_str())
+        .call()?
+        .into_string()?;
+    let user = logged_in_user(&Html::parse_document(&page))?
+        .ok_or_else(|| anyhow!("The session token is not logged in, it may have expired"))?;
+
+    println!("Logged in as {}", user);
+    save_session(&sessions_file, profile_name(), &session)?;
+    println!(
+        "Saved the session for profile {} to {}",
+        profile_name(),
+        sessions_file.display()
+    );
+    Ok(())
+}
+
+// The header shows the user's name followed by their star count when logged in
+fn logged_in_user(page: &Html) -> Result<Option<String>> {
+    let user_selector = selector("header div.user")?;
+    Ok(page
+        .select(&user_selector)
+        .next()
+        .and_then(|user| user.text().next())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty()))
+}
+
+#[derive(Args)]
+struct ShowArguments {
+    #[arg(
+        value_parser = parse_day,
+        help = "The day to show the puzzle description of, either as a number or as dayNN"
+    )]
+    day: usize,
+
+    #[arg(
+        short,
+        long,
+        env = "AOC_YEAR",
+        default_value_t = YEAR,
+        help = "The event year the description was saved for."
+    )]
+    year: u16,
+}
+
+pub fn show_command() -> Command {
+    ShowArguments::augment_args(Command::new("show"))
+        .about("Prints the puzzle description saved by download_input --description")
+        .arg_required_else_help(true)
+}
+
+pub fn show(args: &ArgMatches) -> Result<()> {
+    let arguments = ShowArguments::parse_output(args);
+    let description_file = description_file(arguments.year, arguments.day);
+
+    if !description_file.exists() {
+        return Err(anyhow!(
+            "No description saved for day {}, download it with download_input --description",
+            arguments.day
+        ));
+    }
+
+    println!("{}", read_to_string(description_file)?);
+    Ok(())
+}
+
+pub fn submit(
+    args: &ArgMatches,
+    solve: impl Fn(usize, usize) -> Result<ProblemResult>,
+) -> Result<()> {
+    let arguments = SubmitArguments::parse_output(args);
+    let part = arguments.part as usize;
+
+    let year = arguments.site.year;
+
+    let answer = match arguments.answer {
+        Some(answer) => answer,
+        None if year == YEAR => solve(arguments.day, part - 1)?.to_string(),
+        None => {
+            return Err(anyhow!(
+                "The solvers are for {}, pass --answer to submit for {}",
+                YEAR,
+                year
+            ))
+        }
+    };
+
+    let submissions_file = submissions_file(year, arguments.day);
+    let submissions = read_submissions(&submissions_file)?;
+    if let Some(reason) = already_answered(&submissions, part, &answer) {
+        println!("Not submitting {}, {}", answer, reason);
+        return Ok(());
+    }
+
+    let site = PuzzleSite::new(arguments.site)?;
+
+    println!(
+        "Submitting {} for day {} part {}",
+        answer, arguments.day, part
+    );
+    let outcome = submit_answer(&site, arguments.day, part, &answer)?;
+    println!("{}", outcome);
+
+    record_submission(
+        &submissions_file,
+        &Submission {
+            part,
+            answer,
+            outcome,
+        },
+    )
+}
+
+fn fetch_and_save_input_file(site: &PuzzleSite, day: usize, force: bool) -> Result<()> {
+    let input_file = &input_file(site.year, day);
 
     if input_file.exists() && !force {
         let confirm = Confirm::new()
@@ -106,17 +468,115 @@ fn fetch_and_save_input_file(agent: &Agent, url: &Url, day: usize, force: bool)
     }
 
     println!("Downloading the input file");
-    let result = agent
-        .get(&format!("{}2024/day/{}/input", url.as_str(), day))
-        .call()?
-        .into_string()?;
+    let result = site.cache.get(
+        &site.agent,
+        &site.day_url(day, "/input")?,
+        CachePolicy::Immutable,
+    )?;
 
     println!("Saving file to disk");
     save_string_to_file(&result, input_file).map_err(|e| e.into())
 }
 
-fn fetch_and_save_samples(agent: &Agent, url: &Url, day: usize, force: bool) -> Result<()> {
-    let sample_file = sample_file_from_index(day, 0);
+// Puzzles unlock at midnight US-Eastern on each day of December
+fn unlock_time(year: u16, day: usize) -> Result<DateTime<Utc>> {
+    New_York
+        .with_ymd_and_hms(year.into(), 12, day.try_into()?, 0, 0, 0)
+        .single()
+        .map(|unlock| unlock.with_timezone(&Utc))
+        .ok_or_else(|| anyhow!("Day {} has no unlock time in {}", day, year))
+}
+
+fn wait_for_unlock(unlock: DateTime<Utc>) -> Result<()> {
+    println!("Day unlocks at {}", unlock.with_timezone(&Local));
+
+    while let Some(remaining) = (unlock - Utc::now()).to_std().ok().filter(|r| !r.is_zero()) {
+        let seconds = remaining.as_secs();
+        print!(
+            "\rUnlocks in {:02}:{:02}:{:02} ",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        );
+        io::stdout().flush()?;
+        thread::sleep(remaining.min(Duration::from_secs(1)));
+    }
+
+    println!("\rUnlocked            ");
+    Ok(())
+}
+
+const UNLOCK_RETRIES: u32 = 6;
+
+// The input can 404 for a moment after unlock, so back off and try again before giving up
+fn fetch_input_when_available(site: &PuzzleSite, day: usize, force: bool) -> Result<()> {
+    let mut attempt = 0;
+    loop {
+        match fetch_and_save_input_file(site, day, force) {
+            Err(e) if is_not_found(&e) && attempt + 1 < UNLOCK_RETRIES => {
+                let backoff = Duration::from_secs(1 << attempt);
+                println!("Input is not available yet, retrying in {:#?}", backoff);
+                thread::sleep(backoff);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+fn is_not_found(error: &anyhow::Error) -> bool {
+    matches!(
+        error.downcast_ref::<ureq::Error>(),
+        Some(ureq::Error::Status(404, _))
+    )
+}
+
+#[test]
+fn unlocks_at_midnight_eastern() {
+    assert_eq!(
+        unlock_time(2024, 1).expect("Valid day"),
+        Utc.with_ymd_and_hms(2024, 12, 1, 5, 0, 0).unwrap()
+    );
+    assert_eq!(
+        unlock_time(2015, 25).expect("Valid day"),
+        Utc.with_ymd_and_hms(2015, 12, 25, 5, 0, 0).unwrap()
+    );
+    assert!(unlock_time(2024, 32).is_err());
+}
+
+// Part two's article only appears once it is unlocked, so saving again later adds it
+fn save_description(page: &Html, year: u16, day: usize) -> Result<()> {
+    let article_selector = selector("article.day-desc")?;
+    let description = page
+        .select(&article_selector)
+        .map(|article| html_to_markdown(&article))
+        .join("\n\n");
+
+    let file_name = description_file(year, day);
+    println!("Saving description to {}", file_name.display());
+    save_string_to_file(&format!("{}\n", description), &file_name).map_err(|e| e.into())
+}
+
+// Other years get their own directory so they never overwrite the files the solvers read
+fn day_name(year: u16, day: usize) -> String {
+    match year {
+        YEAR => format!("day{:0>2}", day),
+        year => format!("{}/day{:0>2}", year, day),
+    }
+}
+
+fn input_file(year: u16, day: usize) -> PathBuf {
+    input_directory(&day_name(year, day)).join("input.txt")
+}
+
+fn description_file(year: u16, day: usize) -> PathBuf {
+    PathBuf::from("input")
+        .join(day_name(year, day))
+        .join("puzzle.md")
+}
+
+fn save_samples(html: &Html, year: u16, day: usize, force: bool, interactive: bool) -> Result<()> {
+    let sample_file = sample_file_from_index(year, day, 0);
 
     if sample_file.exists() && !force {
         let confirm = Confirm::new()
@@ -129,15 +589,48 @@ fn fetch_and_save_samples(agent: &Agent, url: &Url, day: usize, force: bool) ->
         }
     }
 
-    println!("Downloading the page information");
-    let result = agent
-        .get(&format!("{}2024/day/{}", url.as_str(), day))
-        .call()?
-        .into_string()?;
+    if interactive {
+        return prompt_for_samples(html, year, day);
+    }
+
+    let input_file = input_file(year, day);
+    let input = input_file
+        .exists()
+        .then(|| read_to_string(&input_file))
+        .transpose()?;
+    let samples = find_samples(html, input.as_deref())?;
+
+    if samples.is_empty() {
+        println!("No samples could be detected");
+        return prompt_for_samples(html, year, day);
+    }
+
+    samples
+        .into_iter()
+        .enumerate()
+        .try_for_each(|(index, sample)| {
+            let file_name = sample_file_from_index(year, day, index);
+            println!("Saving sample to {}:", file_name.display());
+            println!("{}", sample.text);
+            save_string_to_file(&sample.text, &file_name)?;
+
+            if sample.answers.is_empty() {
+                return Ok(());
+            }
+
+            let answers = sample
+                .answers
+                .iter()
+                .map(|(part, answer)| format!("{}: {}\n", PART_NAMES[*part], answer))
+                .collect::<String>();
+            print!("{}", answers);
+            save_string_to_file(&answers, &file_name.with_extension("answers"))
+                .map_err(|e| e.into())
+        })
+}
 
-    let html = Html::parse_document(&result);
-    let code_blocks_selector = Selector::parse("code")
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e.to_string()))?;
+fn prompt_for_samples(html: &Html, year: u16, day: usize) -> Result<()> {
+    let code_blocks_selector = selector("code")?;
 
     let mut sample_index = 0;
 
@@ -151,7 +644,7 @@ fn fetch_and_save_samples(agent: &Agent, url: &Url, day: usize, force: bool) ->
                 .map_err(anyhow::Error::new)
                 .and_then(|is_sample| {
                     if is_sample {
-                        let file_name = sample_file_from_index(day, sample_index);
+                        let file_name = sample_file_from_index(year, day, sample_index);
                         println!(
                             "Saving file to {}",
                             file_name.to_str().expect("path exists")
@@ -176,13 +669,526 @@ fn fetch_and_save_samples(agent: &Agent, url: &Url, day: usize, force: bool) ->
     Ok(())
 }
 
-fn sample_file_from_index(day: usize, index: usize) -> PathBuf {
+struct Sample {
+    text: String,
+    answers: Vec<(usize, String)>,
+}
+
+// A code block is taken as a sample when at least two of these hold: it is introduced as an
+// example, it spans multiple lines, or it only uses the kinds of characters the input uses.
+// Each part's answer is the last emphasized code in its article, matched to the part's last sample
+fn find_samples(html: &Html, input: Option<&str>) -> Result<Vec<Sample>> {
+    let article_selector = selector("article.day-desc")?;
+    let block_selector = selector("pre > code")?;
+    let answer_selector = selector("code > em")?;
+    let input_shape = input.map(line_shape);
+
+    Ok(html
+        .select(&article_selector)
+        .take(PART_NAMES.len())
+        .enumerate()
+        .fold(Vec::<Sample>::new(), |mut samples, (part, article)| {
+            let found = article
+                .select(&block_selector)
+                .filter(|block| is_sample(block, input_shape.as_ref()))
+                .map(|block| {
+                    let text = block.text().collect::<String>();
+                    samples
+                        .iter()
+                        .position(|sample| sample.text == text)
+                        .unwrap_or_else(|| {
+                            samples.push(Sample {
+                                text,
+                                answers: Vec::new(),
+                            });
+                            samples.len() - 1
+                        })
+                })
+                .last()
+                .or(samples.len().checked_sub(1));
+
+            let answer = article
+                .select(&answer_selector)
+                .last()
+                .map(|answer| answer.text().collect::<String>());
+
+            if let Some((index, answer)) = found.zip(answer) {
+                samples[index].answers.push((part, answer));
+            }
+
+            samples
+        }))
+}
+
+fn is_sample(block: &ElementRef, input_shape: Option<&BTreeSet<char>>) -> bool {
+    let text = block.text().collect::<String>();
+
+    let introduced = block
+        .parent()
+        .and_then(|pre| pre.prev_siblings().find_map(ElementRef::wrap))
+        .is_some_and(|paragraph| {
+            paragraph
+                .text()
+                .collect::<String>()
+                .to_lowercase()
+                .contains("for example")
+        });
+    let multi_line = text.trim_end().lines().count() > 1;
+    let matches_input = input_shape.is_some_and(|shape| line_shape(&text).is_subset(shape));
+
+    [introduced, multi_line, matches_input]
+        .into_iter()
+        .filter(|signal| *signal)
+        .count()
+        >= 2
+}
+
+fn line_shape(text: &str) -> BTreeSet<char> {
+    text.chars()
+        .filter(|c| *c != '\n' && *c != '\r')
+        .map(|c| match c {
+            '0'..='9' => '0',
+            'a'..='z' => 'a',
+            'A'..='Z' => 'A',
+            c => c,
+        })
+        .collect()
+}
+
+fn sample_file_from_index(year: u16, day: usize, index: usize) -> PathBuf {
     let sample_number = if index == 0 {
         "".to_string()
     } else {
         (index + 1).to_string()
     };
 
-    PathBuf::new()
-        .tap_mut(|path| path.push(format!("input/day{:0>2}/sample{}.txt", day, sample_number)))
+    PathBuf::from("input")
+        .join(day_name(year, day))
+        .join(format!("sample{}.txt", sample_number))
+}
+
+fn selector(selectors: &str) -> Result<Selector> {
+    Selector::parse(selectors)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e.to_string()).into())
+}
+
+#[derive(Debug, PartialEq)]
+enum SubmissionOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    RateLimited(Option<Duration>),
+    AlreadyComplete,
+    Unknown(String),
+}
+
+impl SubmissionOutcome {
+    fn from_record(record: &str) -> Self {
+        match record {
+            "correct" => SubmissionOutcome::Correct,
+            "too high" => SubmissionOutcome::TooHigh,
+            "too low" => SubmissionOutcome::TooLow,
+            "incorrect" => SubmissionOutcome::Incorrect,
+            "already complete" => SubmissionOutcome::AlreadyComplete,
+            record => record
+                .strip_prefix("rate limited")
+                .map(|wait| {
+                    SubmissionOutcome::RateLimited(
+                        wait.trim().parse().ok().map(Duration::from_secs),
+                    )
+                })
+                .unwrap_or_else(|| SubmissionOutcome::Unknown(record.to_string())),
+        }
+    }
+
+    fn to_record(&self) -> String {
+        match self {
+            SubmissionOutcome::Correct => "correct".to_string(),
+            SubmissionOutcome::TooHigh => "too high".to_string(),
+            SubmissionOutcome::TooLow => "too low".to_string(),
+            SubmissionOutcome::Incorrect => "incorrect".to_string(),
+            SubmissionOutcome::RateLimited(Some(wait)) => {
+                format!("rate limited {}", wait.as_secs())
+            }
+            SubmissionOutcome::RateLimited(None) => "rate limited".to_string(),
+            SubmissionOutcome::AlreadyComplete => "already complete".to_string(),
+            SubmissionOutcome::Unknown(message) => message.replace(['\t', '\n'], " "),
+        }
+    }
+}
+
+impl Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmissionOutcome::Correct => write!(f, "That's the right answer!"),
+            SubmissionOutcome::TooHigh => write!(f, "That's not the right answer, it's too high."),
+            SubmissionOutcome::TooLow => write!(f, "That's not the right answer, it's too low."),
+            SubmissionOutcome::Incorrect => write!(f, "That's not the right answer."),
+            SubmissionOutcome::RateLimited(Some(wait)) => {
+                write!(
+                    f,
+                    "Answered too recently, wait {:#?} before trying again.",
+                    wait
+                )
+            }
+            SubmissionOutcome::RateLimited(None) => {
+                write!(f, "Answered too recently, wait before trying again.")
+            }
+            SubmissionOutcome::AlreadyComplete => write!(f, "This part is already complete."),
+            SubmissionOutcome::Unknown(message) => write!(f, "Unknown response: {}", message),
+        }
+    }
+}
+
+fn submit_answer(
+    site: &PuzzleSite,
+    day: usize,
+    part: usize,
+    answer: &str,
+) -> Result<SubmissionOutcome> {
+    site.cache.throttle()?;
+    let result = site
+        .agent
+        .post(site.day_url(day, "/answer")?.as_str())
+        .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+        .into_string()?;
+
+    parse_submission_response(&result)
+}
+
+fn parse_submission_response(response: &str) -> Result<SubmissionOutcome> {
+    let html = Html::parse_document(response);
+    let article_selector = selector("main article")?;
+    let message = html
+        .select(&article_selector)
+        .flat_map(|article| article.text())
+        .collect::<String>();
+
+    Ok(if message.contains("That's the right answer") {
+        SubmissionOutcome::Correct
+    } else if message.contains("You gave an answer too recently") {
+        SubmissionOutcome::RateLimited(
+            message
+                .split_once("You have ")
+                .and_then(|(_, wait)| parse_wait().lazy().parse(wait).into_output()),
+        )
+    } else if message.contains("That's not the right answer") {
+        if message.contains("too high") {
+            SubmissionOutcome::TooHigh
+        } else if message.contains("too low") {
+            SubmissionOutcome::TooLow
+        } else {
+            SubmissionOutcome::Incorrect
+        }
+    } else if message.contains("You don't seem to be solving the right level") {
+        SubmissionOutcome::AlreadyComplete
+    } else {
+        SubmissionOutcome::Unknown(message.trim().to_string())
+    })
+}
+
+fn parse_wait<'a>() -> impl Parser<'a, &'a str, Duration, extra::Err<Rich<'a, char>>> {
+    parse_usize()
+        .then(one_of("hms"))
+        .map(|(amount, unit)| match unit {
+            'h' => amount * 60 * 60,
+            'm' => amount * 60,
+            _ => amount,
+        })
+        .separated_by(just(' '))
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .map(|seconds| Duration::from_secs(seconds.into_iter().sum::<usize>() as u64))
+}
+
+struct Submission {
+    part: usize,
+    answer: String,
+    outcome: SubmissionOutcome,
+}
+
+fn submissions_file(year: u16, day: usize) -> PathBuf {
+    input_directory(&day_name(year, day)).join("submissions.txt")
+}
+
+// Each line is tab separated: time, part, answer, outcome. Answers such as grids can span lines,
+// so their tabs, newlines and backslashes are escaped
+fn read_submissions(file: &PathBuf) -> Result<Vec<Submission>> {
+    if !file.exists() {
+        return Ok(Vec::new());
+    }
+
+    read_to_string(file)?
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| match line.split('\t').collect::<Vec<_>>()[..] {
+            [_, part, answer, outcome] => Ok(Submission {
+                part: part.parse()?,
+                answer: unescape_answer(answer),
+                outcome: SubmissionOutcome::from_record(outcome),
+            }),
+            _ => Err(anyhow!("Invalid submission record: {}", line)),
+        })
+        .collect()
+}
+
+fn record_submission(file: &PathBuf, submission: &Submission) -> Result<()> {
+    if let Some(parent) = file.parent() {
+        create_dir_all(parent)?;
+    }
+
+    writeln!(
+        OpenOptions::new().create(true).append(true).open(file)?,
+        "{}\t{}\t{}\t{}",
+        Local::now().to_rfc3339(),
+        submission.part,
+        escape_answer(&submission.answer),
+        submission.outcome.to_record()
+    )
+    .map_err(|e| e.into())
+}
+
+fn escape_answer(answer: &str) -> String {
+    answer
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn unescape_answer(answer: &str) -> String {
+    let mut characters = answer.chars();
+    let mut unescaped = String::with_capacity(answer.len());
+    while let Some(character) = characters.next() {
+        unescaped.push(match (character, characters.clone().next()) {
+            ('\\', Some(escaped @ ('\\' | 't' | 'n' | 'r'))) => {
+                characters.next();
+                match escaped {
+                    't' => '\t',
+                    'n' => '\n',
+                    'r' => '\r',
+                    escaped => escaped,
+                }
+            }
+            (character, _) => character,
+        });
+    }
+    unescaped
+}
+
+// Avoids spending a submission on answers the recorded outcomes already rule out
+fn already_answered(submissions: &[Submission], part: usize, answer: &str) -> Option<String> {
+    let number = answer.parse::<i128>().ok();
+
+    submissions
+        .iter()
+        .filter(|submission| submission.part == part)
+        .find_map(|submission| {
+            let previous = submission.answer.parse::<i128>().ok();
+            match (&submission.outcome, number.zip(previous)) {
+                (SubmissionOutcome::Correct, _) => Some(format!(
+                    "part {} was already solved with {}",
+                    part, submission.answer
+                )),
+                (SubmissionOutcome::TooHigh, Some((number, previous))) if number >= previous => {
+                    Some(format!("{} was already too high", submission.answer))
+                }
+                (SubmissionOutcome::TooLow, Some((number, previous))) if number <= previous => {
+                    Some(format!("{} was already too low", submission.answer))
+                }
+                (
+                    SubmissionOutcome::TooHigh
+                    | SubmissionOutcome::TooLow
+                    | SubmissionOutcome::Incorrect,
+                    _,
+                ) if submission.answer == answer => {
+                    Some(format!("{} was already incorrect", submission.answer))
+                }
+                _ => None,
+            }
+        })
+}
+
+#[test]
+fn saves_other_years_apart() {
+    assert_eq!(
+        sample_file_from_index(YEAR, 5, 1),
+        PathBuf::from("input/day05/sample2.txt")
+    );
+    assert_eq!(
+        description_file(2023, 5),
+        PathBuf::from("input/2023/day05/puzzle.md")
+    );
+}
+
+#[test]
+fn parses_submission_responses() {
+    let page = |message: &str| {
+        format!(
+            "<html><body><main><article><p>{}</p></article></main></body></html>",
+            message
+        )
+    };
+
+    assert_eq!(
+        parse_submission_response(&page(
+            "That's the right answer! You are one gold star closer."
+        ))
+        .ok(),
+        Some(SubmissionOutcome::Correct)
+    );
+    assert_eq!(
+        parse_submission_response(&page(
+            "That's not the right answer; your answer is too low. Please wait one minute before trying again."
+        ))
+        .ok(),
+        Some(SubmissionOutcome::TooLow)
+    );
+    assert_eq!(
+        parse_submission_response(&page(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 5s left to wait."
+        ))
+        .ok(),
+        Some(SubmissionOutcome::RateLimited(Some(Duration::from_secs(65))))
+    );
+}
+
+#[test]
+fn detects_samples_and_answers() {
+    let page = Html::parse_document(
+        "<main>
+        <article class=\"day-desc\"><h2>--- Day 1 ---</h2>
+        <p>Reports are lists of <code>numbers</code>.</p>
+        <p>For example:</p>
+        <pre><code>7 6 4\n1 2 7\n</code></pre>
+        <p>A note that is not a sample:</p>
+        <pre><code>Safe\nUnsafe\n</code></pre>
+        <p>In this example, <code><em>2</em></code> reports are safe.</p>
+        </article>
+        <article class=\"day-desc\"><h2>--- Part Two ---</h2>
+        <p>Now, <code><em>4</em></code> reports are safe.</p>
+        </article>
+        </main>",
+    );
+
+    let samples = find_samples(&page, Some("1 2 3\n4 5 6\n")).expect("Finds samples");
+
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].text, "7 6 4\n1 2 7\n");
+    assert_eq!(
+        samples[0].answers,
+        vec![(0, "2".to_string()), (1, "4".to_string())]
+    );
+}
+
+#[test]
+fn skips_answers_ruled_out_by_previous_submissions() {
+    let submissions = [
+        (1, "100", SubmissionOutcome::TooHigh),
+        (1, "10", SubmissionOutcome::TooLow),
+        (1, "50", SubmissionOutcome::Incorrect),
+        (2, "abc", SubmissionOutcome::Correct),
+    ]
+    .into_iter()
+    .map(|(part, answer, outcome)| Submission {
+        part,
+        answer: answer.to_string(),
+        outcome: SubmissionOutcome::from_record(&outcome.to_record()),
+    })
+    .collect::<Vec<_>>();
+
+    assert!(already_answered(&submissions, 1, "150").is_some());
+    assert!(already_answered(&submissions, 1, "5").is_some());
+    assert!(already_answered(&submissions, 1, "50").is_some());
+    assert!(already_answered(&submissions, 1, "51").is_none());
+    assert!(already_answered(&submissions, 2, "def").is_some());
+}
+
+#[test]
+fn records_answers_spanning_lines() {
+    let file = std::env::temp_dir()
+        .join(format!("submissions_{}", std::process::id()))
+        .join("submissions.txt");
+    let answer = "#.\t\\n\n.#".to_string();
+
+    record_submission(
+        &file,
+        &Submission {
+            part: 1,
+            answer: answer.clone(),
+            outcome: SubmissionOutcome::Incorrect,
+        },
+    )
+    .expect("Records");
+    let submissions = read_submissions(&file).expect("Reads");
+    std::fs::remove_dir_all(file.parent().expect("Has directory")).expect("Cleans up");
+
+    assert_eq!(submissions.len(), 1);
+    assert_eq!(submissions[0].answer, answer);
+}
+
+#[test]
+fn submits_answers_to_the_answer_endpoint() {
+    use std::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Binds");
+    let base_url = format!(
+        "http://127.0.0.1:{}/events",
+        listener.local_addr().expect("Has address").port()
+    );
+
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().expect("Connects");
+        let request = crate::libs::http_cache::read_request(&mut stream);
+
+        let body = "<main><article><p>That's not the right answer; your answer is too high.</p></article></main>";
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )
+        .expect("Writes");
+        request
+    });
+
+    let cache_dir = std::env::temp_dir().join(format!("submit_{}", std::process::id()));
+    let site = PuzzleSite::new(SiteArguments {
+        session: Some("token".to_string()),
+        sessions_file: None,
+        base_url: parse_base_url(&base_url).expect("Valid url"),
+        year: 2015,
+        cache_dir: cache_dir.clone(),
+        request_interval: 0,
+        user_agent: USER_AGENT.to_string(),
+    })
+    .expect("Creates site");
+    let outcome = submit_answer(&site, 5, 2, "1234").expect("Submits");
+    let request = server.join().expect("Server finishes");
+
+    assert_eq!(outcome, SubmissionOutcome::TooHigh);
+    assert!(request.starts_with("POST /events/2015/day/5/answer HTTP/1.1"));
+    assert!(request.contains("session=token"));
+    assert!(request.contains(USER_AGENT));
+    assert!(request.ends_with("level=2&answer=1234"));
+    std::fs::remove_dir_all(cache_dir).expect("Cleans up");
+}
+
+#[test]
+fn detects_logged_in_user() {
+    let logged_in = Html::parse_document(
+        "<header><div><h1 class=\"title-global\"><a href=\"/\">Advent of Code</a></h1>
+        <div class=\"user\">Historian <span class=\"star-count\">50*</span></div></div></header>",
+    );
+    let logged_out = Html::parse_document(
+        "<header><div><h1 class=\"title-global\"><a href=\"/\">Advent of Code</a></h1>
+        <nav><ul><li><a href=\"/2024/auth/login\">[Log In]</a></li></ul></nav></div></header>",
+    );
+
+    assert_eq!(
+        logged_in_user(&logged_in).expect("Valid selector"),
+        Some("Historian".to_string())
+    );
+    assert_eq!(logged_in_user(&logged_out).expect("Valid selector"), None);
 }
@@ -0,0 +1,110 @@
+use std::marker::PhantomData;
+
+use chumsky::{
+    error::Rich,
+    extra,
+    primitive::one_of,
+    text::{self, newline},
+    IterParser, Parser,
+};
+use ndarray::Array2;
+
+use super::parse::ParseError;
+
+pub trait ByteParse<'a>: Sized {
+    fn parse() -> impl Parser<'a, &'a [u8], Self, extra::Err<Rich<'a, u8>>>;
+}
+
+// Names the parsed input for any lifetime of the byte buffer, so inputs can borrow from it
+pub trait ByteInput {
+    type Borrowed<'a>: ByteParse<'a>;
+}
+
+// Marks a problem whose input is read and parsed as raw bytes instead of a string
+pub struct Bytes<T>(PhantomData<T>);
+
+pub struct ByteParser<T>(pub T);
+
+impl<'a, T> TryFrom<&'a [u8]> for ByteParser<T>
+where
+    T: ByteParse<'a>,
+{
+    type Error = anyhow::Error;
+
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        T::parse()
+            .map(ByteParser)
+            .parse(value)
+            .into_result()
+            .map_err(|e| ParseError::from_bytes(value, e).into())
+    }
+}
+
+pub fn parse_usize<'a>() -> impl Parser<'a, &'a [u8], usize, extra::Err<Rich<'a, u8>>> {
+    text::int(10).try_map(|number: &'a [u8], span| {
+        number
+            .iter()
+            .try_fold(0_usize, |acc, digit| {
+                acc.checked_mul(10)?.checked_add((digit - b'0') as usize)
+            })
+            .ok_or_else(|| Rich::custom(span, "number too large to fit in target type"))
+    })
+}
+
+pub fn parse_digit<'a>() -> impl Parser<'a, &'a [u8], u8, extra::Err<Rich<'a, u8>>> {
+    one_of(b'0'..=b'9').map(|digit| digit - b'0')
+}
+
+pub fn parse_lines<'a, T>(
+    line_parser: impl Parser<'a, &'a [u8], T, extra::Err<Rich<'a, u8>>>,
+) -> impl Parser<'a, &'a [u8], Vec<T>, extra::Err<Rich<'a, u8>>> {
+    line_parser.separated_by(newline()).collect::<Vec<_>>()
+}
+
+pub fn parse_table<'a, T>(
+    item_parser: impl Parser<'a, &'a [u8], T, extra::Err<Rich<'a, u8>>>,
+) -> impl Parser<'a, &'a [u8], Vec<Vec<T>>, extra::Err<Rich<'a, u8>>> {
+    parse_lines(item_parser.repeated().at_least(1).collect())
+}
+
+pub fn parse_table2<'a, T>(
+    item_parser: impl Parser<'a, &'a [u8], T, extra::Err<Rich<'a, u8>>>,
+) -> impl Parser<'a, &'a [u8], Array2<T>, extra::Err<Rich<'a, u8>>> {
+    parse_table(item_parser).try_map(|items, span| {
+        let columns = items.first().map_or(0, |row| row.len());
+        let rows = items.len();
+
+        Array2::from_shape_vec(
+            (rows, columns),
+            items
+                .into_iter()
+                .fold(Vec::with_capacity(rows * columns), |mut acc, row| {
+                    acc.extend(row);
+                    acc
+                }),
+        )
+        .map_err(|op| Rich::custom(span, op))
+    })
+}
+
+#[test]
+fn parses_byte_tables_and_numbers() {
+    let table = parse_table2(parse_digit())
+        .parse(b"012\n345".as_slice())
+        .into_result()
+        .expect("Parses");
+    assert_eq!(
+        table,
+        Array2::from_shape_vec((2, 3), vec![0, 1, 2, 3, 4, 5]).unwrap()
+    );
+
+    assert_eq!(
+        parse_lines(parse_usize())
+            .parse(b"12\n345".as_slice())
+            .into_result(),
+        Ok(vec![12, 345])
+    );
+    assert!(parse_usize()
+        .parse(b"99999999999999999999999".as_slice())
+        .has_errors());
+}
@@ -1,18 +1,32 @@
-use std::{marker::PhantomData, path::PathBuf};
+use std::{
+    convert::Infallible,
+    fs::read_dir,
+    marker::PhantomData,
+    num::ParseIntError,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+    time::{Duration, Instant},
+};
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, Context, Result};
 use clap::{
-    builder::PathBufValueParser, Arg, ArgAction, ArgMatches, Args, Command as ClapCommand,
-    FromArgMatches, ValueHint,
+    builder::PathBufValueParser, parser::ValueSource, value_parser, Arg, ArgAction, ArgMatches,
+    Args, Command as ClapCommand, FromArgMatches, ValueHint,
 };
+use itertools::Itertools;
 use tap::{Conv, Tap};
 
 use super::{
-    file_system::file_to_string,
-    parse::{StringParse, StringParser},
+    byte_parse::{ByteInput, ByteParser, Bytes},
+    config::{configured_budget, missing_required_arg, with_configured_default},
+    file_system::{file_to_bytes, file_to_string, FileBytes},
+    parse::{StringInput, StringParser},
     problem::{Problem, ProblemResult},
+    profile::input_directory,
 };
 
+#[cfg(feature = "profile")]
+use super::cpu_profile::CpuProfile;
 #[cfg(feature = "telemetry")]
 use super::telemetry::RunPartTelemetry;
 
@@ -20,6 +34,9 @@ pub trait CliArgs {
     fn get_args() -> Vec<Arg>;
 
     fn parse_output(args: &ArgMatches) -> Self;
+
+    // Changes only the arguments present in args, for overriding a part's arguments
+    fn update_output(&mut self, args: &ArgMatches);
 }
 
 impl<T> CliArgs for T
@@ -36,6 +53,78 @@ where
     fn parse_output(args: &ArgMatches) -> Self {
         T::from_arg_matches(args).expect("Valid arguments")
     }
+
+    fn update_output(&mut self, args: &ArgMatches) {
+        self.update_from_arg_matches(args).expect("Valid arguments")
+    }
+}
+
+pub trait FileInput {
+    type Buffer;
+    type Borrowed<'a>;
+
+    fn read(file: &Path) -> Result<Self::Buffer>;
+
+    fn parse(buffer: &Self::Buffer) -> Result<Self::Borrowed<'_>>;
+}
+
+impl<T> FileInput for T
+where
+    T: StringInput,
+{
+    type Buffer = String;
+    type Borrowed<'a> = T::Borrowed<'a>;
+
+    fn read(file: &Path) -> Result<Self::Buffer> {
+        file_to_string(file).map_err(|e| e.into())
+    }
+
+    fn parse(buffer: &Self::Buffer) -> Result<Self::Borrowed<'_>> {
+        StringParser::try_from(buffer.as_str()).map(|input| input.0)
+    }
+}
+
+impl<T> FileInput for Bytes<T>
+where
+    T: ByteInput,
+{
+    type Buffer = FileBytes;
+    type Borrowed<'a> = T::Borrowed<'a>;
+
+    fn read(file: &Path) -> Result<Self::Buffer> {
+        file_to_bytes(file).map_err(|e| e.into())
+    }
+
+    fn parse(buffer: &Self::Buffer) -> Result<Self::Borrowed<'_>> {
+        ByteParser::try_from(&buffer[..]).map(|input| input.0)
+    }
+}
+
+pub trait ProblemFile<A> {
+    type Output: Into<ProblemResult> + Clone;
+
+    fn run_file(file: &Path, arguments: &A) -> Result<ProblemResult>;
+}
+
+impl<I, A> ProblemFile<A> for I
+where
+    I: FileInput,
+    for<'a> I::Borrowed<'a>: Problem<A>,
+{
+    type Output = <I::Borrowed<'static> as Problem<A>>::Output;
+
+    fn run_file(file: &Path, arguments: &A) -> Result<ProblemResult> {
+        let buffer = I::read(file)?;
+        let input = {
+            #[cfg(feature = "telemetry")]
+            let _parse = RunPartTelemetry::time_parse();
+            I::parse(&buffer)
+        }?;
+
+        #[cfg(feature = "telemetry")]
+        let _run = RunPartTelemetry::time_run();
+        Ok(input.run(arguments).into())
+    }
 }
 
 pub trait Command {
@@ -45,11 +134,55 @@ pub trait Command {
 
     fn run_part(&self, part: usize) -> Result<ProblemResult>;
 
+    fn check_samples(&self, part: usize) -> Vec<SampleCheck>;
+
+    fn get_budget(&self, part: usize) -> Duration;
+
     fn get_name(&self) -> &'static str;
 
     fn get_subcommand(&self) -> ClapCommand;
+
+    fn run_selection(&self, selection: &RunSelection) -> Result<ProblemResult>;
+}
+
+#[derive(Clone)]
+pub enum InputChoice {
+    Real,
+    Sample,
+    File(PathBuf),
+}
+
+pub fn parse_input_choice(input: &str) -> Result<InputChoice, Infallible> {
+    Ok(match input {
+        "real" => InputChoice::Real,
+        "sample" => InputChoice::Sample,
+        file => InputChoice::File(PathBuf::from(file)),
+    })
 }
 
+// What the run subcommand asked for: a part's arguments, which input and any argument overrides
+pub struct RunSelection {
+    pub part: Option<usize>,
+    pub input: InputChoice,
+    pub overrides: Vec<String>,
+}
+
+pub struct SampleCheck {
+    pub file: &'static str,
+    pub expected: ProblemResult,
+    pub actual: Result<ProblemResult>,
+    pub elapsed: Duration,
+}
+
+impl SampleCheck {
+    pub fn passed(&self) -> bool {
+        self.actual
+            .as_ref()
+            .is_ok_and(|actual| *actual == self.expected)
+    }
+}
+
+#[cfg_attr(not(feature = "all-days"), allow(dead_code))]
 pub trait AsCommand: Command {
     fn as_command(&self) -> &dyn Command;
 }
@@ -60,6 +193,19 @@ impl<T: Command> AsCommand for T {
     }
 }
 
+// Each day submits one of these next to its static so main can find every compiled day
+pub struct RegisteredDay(pub fn() -> &'static dyn Command);
+
+inventory::collect!(RegisteredDay);
+
+pub fn registered_days() -> Vec<&'static dyn Command> {
+    inventory::iter::<RegisteredDay>
+        .into_iter()
+        .map(|RegisteredDay(day)| day())
+        .sorted_by_key(|day| day.get_name())
+        .collect()
+}
+
 pub enum Thaw {}
 
 pub enum Freeze {}
@@ -68,11 +214,37 @@ pub struct Part<T, O> {
     help: &'static str,
     arg: T,
     samples: Vec<(&'static str, O)>,
+    budget: Duration,
+}
+
+static ITERATIONS: OnceLock<u64> = OnceLock::new();
+
+pub fn iterations_arg() -> Arg {
+    Arg::new("iterations")
+        .long("iterations")
+        .global(true)
+        .value_parser(value_parser!(u64).range(1..))
+        .default_value("1")
+        .help(
+            "Run each part this many times, giving fast parts enough time to be profiled or timed",
+        )
+}
+
+pub fn set_iterations(matches: &ArgMatches) {
+    ITERATIONS.get_or_init(|| *matches.get_one::<u64>("iterations").unwrap_or(&1));
+}
+
+pub fn iterations() -> u64 {
+    ITERATIONS.get().copied().unwrap_or(1)
 }
 
+// Fifty parts at this budget keep every day running in under a second
+#[cfg_attr(not(feature = "all-days"), allow(dead_code))]
+pub const DEFAULT_BUDGET: Duration = Duration::from_millis(20);
+
 pub struct CliProblem<I, A, S>
 where
-    I: StringParse + Problem<A>,
+    I: ProblemFile<A>,
     A: CliArgs,
 {
     name: &'static str,
@@ -82,13 +254,14 @@ where
     _marker: PhantomData<(I, S)>,
 }
 
+#[cfg_attr(not(feature = "all-days"), allow(dead_code))]
 pub fn new_cli_problem<I, A>(
     name: &'static str,
     help: &'static str,
     file_help: &'static str,
 ) -> CliProblem<I, A, Thaw>
 where
-    I: StringParse + Problem<A>,
+    I: ProblemFile<A>,
     A: CliArgs,
 {
     CliProblem {
@@ -100,9 +273,10 @@ where
     }
 }
 
+#[cfg_attr(not(feature = "all-days"), allow(dead_code))]
 impl<I, A> CliProblem<I, A, Thaw>
 where
-    I: StringParse + Problem<A>,
+    I: ProblemFile<A>,
     A: CliArgs,
 {
     pub fn with_part(
@@ -111,7 +285,20 @@ where
         arg: A,
         samples: Vec<(&'static str, I::Output)>,
     ) -> Self {
-        self.parts.push(Part { help, arg, samples });
+        self.parts.push(Part {
+            help,
+            arg,
+            samples,
+            budget: DEFAULT_BUDGET,
+        });
+        self
+    }
+
+    // Sets the time budget of the part added last, for parts that need more than the default
+    pub fn with_budget(mut self, budget: Duration) -> Self {
+        if let Some(part) = self.parts.last_mut() {
+            part.budget = budget;
+        }
         self
     }
 
@@ -128,36 +315,234 @@ where
 
 impl<I, A> CliProblem<I, A, Freeze>
 where
-    I: StringParse + Problem<A>,
-    A: CliArgs,
+    I: ProblemFile<A>,
+    A: CliArgs + Clone,
 {
     fn run_with_file_and_args(
         &self,
-        file: &PathBuf,
+        file: &Path,
         args: &A,
-        run_value: &'static str,
+        run_value: &str,
+        budget: Option<Duration>,
+    ) -> Result<ProblemResult> {
+        // The input is consumed by each run so the profile covers parsing as well as solving
+        #[cfg(feature = "profile")]
+        let cpu_profile = CpuProfile::start(self.name, run_value)?;
+
+        let result = (1..iterations())
+            .try_fold(self.run_once(file, args, run_value, budget)?, |_, _| {
+                self.run_once(file, args, run_value, budget)
+            })?;
+
+        #[cfg(feature = "profile")]
+        if let Some(cpu_profile) = cpu_profile {
+            cpu_profile.finish()?;
+        }
+
+        Ok(result)
+    }
+
+    #[cfg_attr(not(feature = "telemetry"), allow(unused_variables))]
+    fn run_once(
+        &self,
+        file: &Path,
+        args: &A,
+        run_value: &str,
+        budget: Option<Duration>,
     ) -> Result<ProblemResult> {
         #[cfg(feature = "telemetry")]
-        let run_part = RunPartTelemetry::new(self.name, run_value);
-        file_to_string(file)
-            .map_err(|e| e.into())
-            .and_then(|f| {
-                #[cfg(feature = "telemetry")]
-                let _parse = run_part.time_parse();
-                StringParser::<I>::try_from(f)
-            })
+        let run_part = RunPartTelemetry::new(self.name, run_value, budget);
+        let result = I::run_file(file, args);
+        #[cfg(feature = "telemetry")]
+        if let Ok(answer) = &result {
+            run_part.record_answer(answer);
+        }
+        result
+    }
+
+    // Runs every input in input/dayNN/inputs, checking each against its label.answers file if it has one
+    fn run_all_inputs(&self, part_index: usize) -> Result<()> {
+        let part = &self.parts[part_index];
+        let inputs_directory = input_directory(self.name).join("inputs");
+        let inputs = read_dir(&inputs_directory)
+            .with_context(|| format!("Reading inputs from {}", inputs_directory.display()))?
+            .map_ok(|entry| entry.path())
+            .filter_ok(|path| path.extension().is_some_and(|extension| extension == "txt"))
+            .collect::<Result<Vec<_>, _>>()?
+            .tap_mut(|inputs| inputs.sort());
+
+        let failures = inputs
+            .iter()
             .map(|input| {
-                #[cfg(feature = "telemetry")]
-                let _run = run_part.time_run();
-                input.0.run(args).into()
+                let label = input
+                    .file_stem()
+                    .map(|label| label.to_string_lossy())
+                    .unwrap_or_default();
+                let answers_file = input.with_extension("answers");
+                let answers = answers_file
+                    .exists()
+                    .then(|| file_to_string(&answers_file))
+                    .transpose()?
+                    .unwrap_or_default();
+                let expected = expected_answer(&answers, part_index);
+                // Each input gets its own report row and profile
+                let run_value = format!("{}-{}", PART_NAMES[part_index], label);
+
+                let failed = match (
+                    self.run_with_file_and_args(input, &part.arg, &run_value, None),
+                    expected,
+                ) {
+                    (Ok(result), Some(expected)) if result.to_string() != expected => {
+                        println!("{}: {}, expected {}", label, result, expected);
+                        true
+                    }
+                    (Ok(result), Some(_)) => {
+                        println!("{}: {}, correct", label, result);
+                        false
+                    }
+                    (Ok(result), None) => {
+                        println!("{}: {}, no expected answer", label, result);
+                        false
+                    }
+                    (Err(e), _) => {
+                        println!("{}: failed, {}", label, e);
+                        true
+                    }
+                };
+                Ok::<_, std::io::Error>(failed)
             })
+            .process_results(|failed| failed.filter(|failed| *failed).count())?;
+
+        match (inputs.len(), failures) {
+            (0, _) => Err(anyhow!("No inputs found in {}", inputs_directory.display())),
+            (_, 0) => Ok(()),
+            (count, failures) => Err(anyhow!("{} of {} inputs did not match", failures, count)),
+        }
     }
 }
 
+impl<I, A> CliProblem<I, A, Freeze>
+where
+    I: ProblemFile<A>,
+    A: CliArgs + Clone,
+{
+    // A part's arguments with the overrides applied, or without a part only the overrides, which
+    // then have to give every argument not set for the file in aoc.toml
+    fn selected_args(&self, part: Option<usize>, overrides: &[String], file: &Path) -> Result<A> {
+        match part {
+            Some(part) => {
+                let mut args = self.parts[part].arg.clone();
+                if !overrides.is_empty() {
+                    args.update_output(&explicit_matches::<A>(self.name, overrides)?);
+                }
+                Ok(args)
+            }
+            None => {
+                let matches = ClapCommand::new(self.name)
+                    .no_binary_name(true)
+                    .arg(file_arg(self.file_help))
+                    .args(
+                        A::get_args()
+                            .into_iter()
+                            .map(|arg| with_configured_default(self.name, arg)),
+                    )
+                    .try_get_matches_from(
+                        ["--file".to_string(), file.display().to_string()]
+                            .into_iter()
+                            .chain(overrides.iter().cloned()),
+                    )?;
+                match missing_required_arg(self.name, &A::get_args(), &matches) {
+                    Some(arg) => Err(anyhow!(
+                        "--{} is required without --part, either pass it or set it for {} in aoc.toml",
+                        arg,
+                        self.name
+                    )),
+                    None => Ok(A::parse_output(&matches)),
+                }
+            }
+        }
+    }
+
+    // Samples are checked against their answers unless overrides change what the part computes
+    fn run_samples(&self, part: usize, overrides: &[String]) -> Result<ProblemResult> {
+        self.parts[part]
+            .samples
+            .iter()
+            .map(|(sample, expected_result)| {
+                let file = PathBuf::from(format!("input/{}/{}", self.name, sample));
+                let args = self.selected_args(Some(part), overrides, &file)?;
+                let result = self.run_with_file_and_args(&file, &args, sample, None)?;
+                let expected_result = expected_result.clone().conv::<ProblemResult>();
+
+                if overrides.is_empty() && result != expected_result {
+                    Err(anyhow!(
+                        "Sample {} did not match. Expected: {}, Actual: {}",
+                        sample,
+                        expected_result,
+                        result
+                    ))
+                } else {
+                    println!("{}: {}", sample, result);
+                    Ok(result)
+                }
+            })
+            .collect::<Result<Vec<_>>>()?
+            .pop()
+            .ok_or_else(|| anyhow!("{} {} has no samples", self.name, PART_NAMES[part]))
+    }
+
+    // A name like sample2 is looked up in input/dayNN when it is not the path of a file
+    fn resolve_input(&self, input: &Path) -> Result<PathBuf> {
+        let directory = PathBuf::from(format!("input/{}", self.name));
+        [
+            input.to_path_buf(),
+            directory.join(input),
+            directory.join(input).with_extension("txt"),
+        ]
+        .into_iter()
+        .find(|path| path.is_file())
+        .ok_or_else(|| {
+            anyhow!(
+                "No input at {} or in {}",
+                input.display(),
+                directory.display()
+            )
+        })
+    }
+}
+
+// Only the arguments given on the command line, so a flag's implicit false cannot replace a part's true
+fn explicit_matches<A: CliArgs>(name: &'static str, overrides: &[String]) -> Result<ArgMatches> {
+    let args = A::get_args();
+    let mut matches = ClapCommand::new(name)
+        .no_binary_name(true)
+        .args(args.iter().cloned().map(|arg| arg.required(false)))
+        .try_get_matches_from(overrides)?;
+
+    args.iter()
+        .map(|arg| arg.get_id().as_str())
+        .filter(|id| matches.value_source(id) == Some(ValueSource::DefaultValue))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .for_each(|id| {
+            let _ = matches.try_remove_one::<bool>(id);
+        });
+    Ok(matches)
+}
+
+// Answers files have a line per part, e.g. "part1: 143"
+fn expected_answer(answers: &str, part_index: usize) -> Option<&str> {
+    answers
+        .lines()
+        .filter_map(|line| line.split_once(": "))
+        .find(|(part, _)| *part == PART_NAMES[part_index])
+        .map(|(_, answer)| answer.trim())
+}
+
 // Frozen problems have no mutable methods so they can be static
 unsafe impl<I, A> Send for CliProblem<I, A, Freeze>
 where
-    I: StringParse + Problem<A>,
+    I: ProblemFile<A>,
     A: CliArgs,
 {
 }
@@ -166,8 +551,8 @@ pub static PART_NAMES: [&str; 2] = ["part1", "part2"];
 
 impl<I, A> Command for CliProblem<I, A, Freeze>
 where
-    I: StringParse + Problem<A>,
-    A: CliArgs,
+    I: ProblemFile<A>,
+    A: CliArgs + Clone,
 {
     fn run(&self, args: &ArgMatches) -> Result<ProblemResult> {
         self.parts
@@ -177,24 +562,14 @@ where
             .find_map(|(name, part)| {
                 args.subcommand_matches(name).map(|args| {
                     if args.get_flag("sample") {
-                        let part = &self.parts[part];
-                        part.samples
-                            .iter()
-                            .map(|(file, expected_result)| {
-                                self.run_with_file_and_args(
-                                    &PathBuf::new().tap_mut(|path| {
-                                        path.push(format!("input/{}/{}", self.name, file))
-                                    }),
-                                    &part.arg,
-                                    file,
-                                )
-                                .and_then(|sample_result| {
-                                    let expected_result =
-                                        (expected_result.clone()).conv::<ProblemResult>();
-                                    if sample_result != expected_result {
+                        self.check_samples(part)
+                            .into_iter()
+                            .map(|check| {
+                                check.actual.and_then(|sample_result| {
+                                    if sample_result != check.expected {
                                         Err(anyhow!(
                                             "Sample did not match. Expected: {}, Actual: {}",
-                                            expected_result,
+                                            check.expected,
                                             sample_result
                                         ))
                                     } else {
@@ -204,14 +579,27 @@ where
                             })
                             .collect::<Result<Vec<_>>>()?;
                     }
+                    if args.get_flag("all-inputs") {
+                        self.run_all_inputs(part)?;
+                    }
                     self.run_part(part)
                 })
             })
             .unwrap_or_else(|| {
+                if let Some(arg) = missing_required_arg(self.name, &A::get_args(), args) {
+                    return Err(anyhow!(
+                        "--{} is required, either pass it or set it for {} in aoc.toml",
+                        arg,
+                        self.name
+                    ));
+                }
                 self.run_with_file_and_args(
-                    args.get_one::<PathBuf>("file").expect("File is required"),
+                    &self.resolve_input(
+                        args.get_one::<PathBuf>("file").expect("File is required"),
+                    )?,
                     &A::parse_output(args),
                     "custom",
+                    None,
                 )
             })
     }
@@ -227,12 +615,42 @@ where
     fn run_part(&self, part_index: usize) -> Result<ProblemResult> {
         let part = &self.parts[part_index];
         self.run_with_file_and_args(
-            &PathBuf::new().tap_mut(|path| path.push(format!("input/{}/input.txt", self.name))),
+            &input_directory(self.name).join("input.txt"),
             &part.arg,
             PART_NAMES[part_index],
+            Some(self.get_budget(part_index)),
         )
     }
 
+    fn check_samples(&self, part_index: usize) -> Vec<SampleCheck> {
+        let part = &self.parts[part_index];
+        part.samples
+            .iter()
+            .map(|(file, expected_result)| {
+                let start = Instant::now();
+                let actual = self.run_with_file_and_args(
+                    &PathBuf::new()
+                        .tap_mut(|path| path.push(format!("input/{}/{}", self.name, file))),
+                    &part.arg,
+                    file,
+                    None,
+                );
+
+                SampleCheck {
+                    file,
+                    expected: expected_result.clone().conv::<ProblemResult>(),
+                    actual,
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect()
+    }
+
+    fn get_budget(&self, part_index: usize) -> Duration {
+        configured_budget(self.name, PART_NAMES[part_index])
+            .unwrap_or(self.parts[part_index].budget)
+    }
+
     fn get_name(&self) -> &'static str {
         self.name
     }
@@ -244,7 +662,11 @@ where
                 .arg_required_else_help(true)
                 .subcommand_negates_reqs(true)
                 .arg(file_arg(self.file_help))
-                .args(A::get_args()),
+                .args(
+                    A::get_args()
+                        .into_iter()
+                        .map(|arg| with_configured_default(self.name, arg)),
+                ),
             |command, (count, part)| {
                 command.subcommand(
                     ClapCommand::new(PART_NAMES[count])
@@ -253,11 +675,55 @@ where
                             's',
                             "Check against the smaples before the real input",
                         ))
+                        .arg(flag_arg(
+                            "all-inputs",
+                            'a',
+                            "Check against every input in input/{day}/inputs before the real input",
+                        ))
                         .about(part.help),
                 )
             },
         )
     }
+
+    fn run_selection(&self, selection: &RunSelection) -> Result<ProblemResult> {
+        let part = selection
+            .part
+            .map(|part| {
+                self.parts
+                    .get(part)
+                    .map(|_| part)
+                    .ok_or_else(|| anyhow!("{} has no part {}", self.name, part + 1))
+            })
+            .transpose()?;
+
+        let file = match &selection.input {
+            InputChoice::Sample => {
+                let part = part.ok_or_else(|| {
+                    anyhow!("--input sample needs --part, since each part has its own samples")
+                })?;
+                return self.run_samples(part, &selection.overrides);
+            }
+            InputChoice::Real => input_directory(self.name).join("input.txt"),
+            InputChoice::File(file) => self.resolve_input(file)?,
+        };
+        let args = self.selected_args(part, &selection.overrides, &file)?;
+
+        // Only a part's own arguments on the real input are held to its budget
+        match (part, &selection.input) {
+            (Some(part), InputChoice::Real) if selection.overrides.is_empty() => self
+                .run_with_file_and_args(
+                    &file,
+                    &args,
+                    PART_NAMES[part],
+                    Some(self.get_budget(part)),
+                ),
+            (Some(part), _) if selection.overrides.is_empty() => {
+                self.run_with_file_and_args(&file, &args, PART_NAMES[part], None)
+            }
+            _ => self.run_with_file_and_args(&file, &args, "custom", None),
+        }
+    }
 }
 
 fn file_arg(help: &str) -> Arg {
@@ -277,6 +743,10 @@ pub fn single_arg(name: &'static str, short: char, help: &str) -> Arg {
         .value_name(name.to_ascii_uppercase())
 }
 
+pub fn parse_day(day: &str) -> Result<usize, ParseIntError> {
+    day.strip_prefix("day").unwrap_or(day).parse()
+}
+
 pub fn flag_arg(name: &'static str, short: char, help: &str) -> Arg {
     Arg::new(name)
         .short(short)
@@ -285,3 +755,43 @@ pub fn flag_arg(name: &'static str, short: char, help: &str) -> Arg {
         .num_args(0)
         .action(ArgAction::SetTrue)
 }
+
+// Whether the argument was given rather than defaulted, e.g. from aoc.toml, so it can win over
+// a conflicting argument that only has a default
+#[cfg_attr(not(feature = "all-days"), allow(dead_code))]
+pub fn is_explicit(args: &ArgMatches, id: &str) -> bool {
+    args.value_source(id)
+        .is_some_and(|source| source != ValueSource::DefaultValue)
+}
+
+#[test]
+fn finds_expected_answers() {
+    let answers = "part1: 143\npart2: 123\n";
+
+    assert_eq!(expected_answer(answers, 0), Some("143"));
+    assert_eq!(expected_answer(answers, 1), Some("123"));
+    assert_eq!(expected_answer("part1: 143\n", 1), None);
+}
+
+#[test]
+fn overrides_only_given_arguments() {
+    #[derive(Args, Clone)]
+    struct Arguments {
+        #[arg(long)]
+        size: usize,
+        #[arg(long)]
+        parallel: bool,
+    }
+
+    let mut arguments = Arguments {
+        size: 7,
+        parallel: true,
+    };
+    arguments.update_output(
+        &explicit_matches::<Arguments>("day00", &["--size".to_string(), "9".to_string()])
+            .expect("Parses"),
+    );
+
+    assert_eq!(arguments.size, 9);
+    assert!(arguments.parallel);
+}
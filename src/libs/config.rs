@@ -0,0 +1,300 @@
+use std::{env, path::PathBuf, sync::OnceLock, time::Duration};
+
+use anyhow::{anyhow, Result};
+use clap::{Arg, ArgMatches, Command as ClapCommand};
+use toml::{Table, Value};
+
+use super::file_system::file_to_string;
+
+struct Config {
+    file: PathBuf,
+    days: Table,
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+// A day's budgets table overrides its parts' time budgets, in milliseconds per part name
+const BUDGETS: &str = "budgets";
+
+// Lives next to the input directory unless AOC_CONFIG points somewhere else
+fn config_file() -> PathBuf {
+    env::var_os("AOC_CONFIG")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("aoc.toml"))
+}
+
+// Must be loaded before the day subcommands are built, since it supplies their defaults
+pub fn load_config() -> Result<()> {
+    let file = config_file();
+    let days = if file.exists() {
+        file_to_string(&file)?
+            .parse::<Table>()
+            .map_err(|e| anyhow!("Could not read {}: {}", file.display(), e))?
+    } else {
+        Table::new()
+    };
+
+    CONFIG.get_or_init(|| Config { file, days });
+    Ok(())
+}
+
+pub fn configured_budget(day: &str, part: &str) -> Option<Duration> {
+    CONFIG
+        .get()
+        .and_then(|config| config.days.get(day))
+        .and_then(|settings| budget(settings.as_table()?, part))
+}
+
+fn budget(settings: &Table, part: &str) -> Option<Duration> {
+    settings
+        .get(BUDGETS)
+        .and_then(|budgets| budgets.get(part))
+        .and_then(|milliseconds| milliseconds.as_integer())
+        .and_then(|milliseconds| milliseconds.try_into().ok())
+        .map(Duration::from_millis)
+}
+
+pub fn with_configured_default(day: &str, arg: Arg) -> Arg {
+    match CONFIG.get().and_then(|config| config.days.get(day)) {
+        Some(Value::Table(settings)) => configured_default(settings, day, arg),
+        _ => arg,
+    }
+}
+
+// A day's table sets the defaults of its custom file arguments, and the tables inside it named after
+// an input file override those when that file is passed as --file, either as given or as a name
+// in input/dayNN. clap only has single conditional defaults, so only day wide values can be lists.
+// clap also only counts arguments passed explicitly as required, so configured arguments are
+// checked by the day instead, see missing_required_arg
+fn configured_default(settings: &Table, day: &str, arg: Arg) -> Arg {
+    let id = arg.get_id().as_str().to_string();
+    let arg = match settings.get(&id) {
+        Some(value) if !value.is_table() => {
+            arg.default_values(value_strings(value)).required(false)
+        }
+        _ => arg,
+    };
+
+    settings
+        .iter()
+        .filter(|(file, _)| *file != BUDGETS)
+        .filter_map(|(file, value)| value.as_table().map(|file_settings| (file, file_settings)))
+        .filter_map(|(file, file_settings)| file_settings.get(&id).map(|value| (file, value)))
+        .fold(arg, |arg, (file, value)| {
+            let value = value_strings(value).concat();
+            arg.default_value_if("file", file.clone(), value.clone())
+                .default_value_if("file", format!("input/{}/{}", day, file), value)
+                .required(false)
+        })
+}
+
+// The first argument the day needs that was neither passed nor configured for this file. Arguments
+// that are still required are left to clap, which also knows when a conflicting argument waives them
+pub fn missing_required_arg(day: &str, args: &[Arg], matches: &ArgMatches) -> Option<String> {
+    missing_configured_arg(args, matches, |arg| with_configured_default(day, arg))
+}
+
+fn missing_configured_arg(
+    args: &[Arg],
+    matches: &ArgMatches,
+    configure: impl Fn(Arg) -> Arg,
+) -> Option<String> {
+    args.iter()
+        .filter(|arg| arg.is_required_set() && !configure((*arg).clone()).is_required_set())
+        .find(|arg| !matches.contains_id(arg.get_id().as_str()))
+        .map(|arg| arg.get_id().to_string())
+}
+
+fn value_strings(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(value) => vec![value.clone()],
+        Value::Array(values) => values.iter().flat_map(value_strings).collect(),
+        value => vec![value.to_string()],
+    }
+}
+
+// Settings that no argument picks up would otherwise be ignored without a word
+pub fn check_config(app: &ClapCommand) -> Result<()> {
+    let Some(config) = CONFIG.get() else {
+        return Ok(());
+    };
+
+    config.days.iter().try_for_each(|(day, settings)| {
+        let settings = settings
+            .as_table()
+            .filter(|_| {
+                day.strip_prefix("day")
+                    .is_some_and(|number| number.parse::<usize>().is_ok())
+            })
+            .ok_or_else(|| {
+                anyhow!(
+                    "{} in {} is not a table of day settings",
+                    day,
+                    config.file.display()
+                )
+            })?;
+        // Days left out of this build have nothing to check against
+        let Some(command) = app.find_subcommand(day) else {
+            return Ok(());
+        };
+
+        settings
+            .get(BUDGETS)
+            .map(|budgets| {
+                budgets
+                    .as_table()
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "{} {} in {} is not a table of part budgets",
+                            day,
+                            BUDGETS,
+                            config.file.display()
+                        )
+                    })?
+                    .iter()
+                    .try_for_each(|(part, milliseconds)| {
+                        if command.find_subcommand(part).is_none()
+                            || !milliseconds.as_integer().is_some_and(|ms| ms > 0)
+                        {
+                            Err(anyhow!(
+                                "{} {} {} in {} must be a part with a positive number of milliseconds",
+                                day,
+                                BUDGETS,
+                                part,
+                                config.file.display()
+                            ))
+                        } else {
+                            Ok(())
+                        }
+                    })
+            })
+            .transpose()?;
+
+        settings
+            .iter()
+            .filter(|(key, _)| *key != BUDGETS)
+            .flat_map(|(key, value)| match value.as_table() {
+                Some(file_settings) => file_settings
+                    .iter()
+                    .map(|(key, value)| (key, value, true))
+                    .collect(),
+                None => vec![(key, value, false)],
+            })
+            .try_for_each(|(key, value, for_file)| {
+                if key == "file"
+                    || !command
+                        .get_arguments()
+                        .any(|arg| arg.get_id() == key.as_str())
+                {
+                    Err(anyhow!(
+                        "{} has no argument {} to set in {}",
+                        day,
+                        key,
+                        config.file.display()
+                    ))
+                } else if for_file && (value.is_array() || value.is_table()) {
+                    Err(anyhow!(
+                        "{} {} can only be a single value for one input file in {}",
+                        day,
+                        key,
+                        config.file.display()
+                    ))
+                } else {
+                    Ok(())
+                }
+            })
+    })
+}
+
+#[test]
+fn sets_defaults_per_day_and_file() {
+    use clap::value_parser;
+
+    let settings = r#"
+        x_size = 70
+        operators = ["add", "multiply"]
+
+        ["big.txt"]
+        x_size = 1000
+    "#
+    .parse::<Table>()
+    .expect("Valid toml");
+    let command = ClapCommand::new("day18").args(
+        [
+            Arg::new("file").long("file"),
+            Arg::new("x_size")
+                .long("x_size")
+                .required(true)
+                .value_parser(value_parser!(usize)),
+            Arg::new("operators").long("operators").num_args(1..),
+        ]
+        .map(|arg| configured_default(&settings, "day18", arg)),
+    );
+    let matches = |args: &[&str]| -> ArgMatches {
+        command
+            .clone()
+            .try_get_matches_from([&["day18"], args].concat())
+            .expect("Parses")
+    };
+
+    assert_eq!(
+        matches(&["--file", "small.txt"]).get_one::<usize>("x_size"),
+        Some(&70)
+    );
+    assert_eq!(
+        matches(&["--file", "big.txt"]).get_one::<usize>("x_size"),
+        Some(&1000)
+    );
+    assert_eq!(
+        matches(&["--file", "input/day18/big.txt"]).get_one::<usize>("x_size"),
+        Some(&1000)
+    );
+    assert_eq!(
+        matches(&["--file", "big.txt", "--x_size", "5"]).get_one::<usize>("x_size"),
+        Some(&5)
+    );
+    let configure = |arg| configured_default(&settings, "day18", arg);
+    assert_eq!(
+        missing_configured_arg(
+            &[Arg::new("x_size").required(true)],
+            &matches(&["--file", "small.txt"]),
+            configure
+        ),
+        None
+    );
+    let only_big = "[\"big.txt\"]\nx_size = 1000"
+        .parse::<Table>()
+        .expect("Valid toml");
+    assert_eq!(
+        missing_configured_arg(
+            &[Arg::new("x_size").required(true)],
+            &ClapCommand::new("day18")
+                .arg(Arg::new("x_size").long("x_size"))
+                .get_matches_from(["day18"]),
+            |arg| configured_default(&only_big, "day18", arg)
+        ),
+        Some("x_size".to_string())
+    );
+    assert_eq!(
+        missing_configured_arg(
+            &[Arg::new("y_size").required(true)],
+            &ClapCommand::new("day18")
+                .arg(Arg::new("y_size").long("y_size"))
+                .get_matches_from(["day18"]),
+            configure
+        ),
+        None
+    );
+    assert_eq!(budget(&settings, "part2"), None);
+    let budgets = "[budgets]\npart2 = 60"
+        .parse::<Table>()
+        .expect("Valid toml");
+    assert_eq!(budget(&budgets, "part2"), Some(Duration::from_millis(60)));
+    assert_eq!(
+        matches(&["--file", "small.txt"])
+            .get_many::<String>("operators")
+            .expect("Has default")
+            .collect::<Vec<_>>(),
+        vec!["add", "multiply"]
+    );
+}
@@ -0,0 +1,90 @@
+use std::{
+    fs::{create_dir_all, write, File},
+    path::PathBuf,
+    sync::OnceLock,
+};
+
+use anyhow::Result;
+use clap::{value_parser, Arg, ArgMatches, ValueEnum};
+use pprof::{protos::Message, ProfilerGuard, ProfilerGuardBuilder};
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ProfileFormat {
+    // An interactive svg that can be opened in a browser
+    Flamegraph,
+    // A protobuf profile for go tool pprof and other pprof viewers
+    Pprof,
+}
+
+static OUTPUT: OnceLock<Option<(PathBuf, ProfileFormat)>> = OnceLock::new();
+
+pub fn profile_args() -> [Arg; 2] {
+    [
+        Arg::new("profile-out")
+            .long("profile-out")
+            .global(true)
+            .value_parser(value_parser!(PathBuf))
+            .help("Sample the cpu while each day part reads, parses and solves its input, and write a profile per part to this directory"),
+        Arg::new("profile-format")
+            .long("profile-format")
+            .global(true)
+            .value_parser(value_parser!(ProfileFormat))
+            .default_value("flamegraph")
+            .help("The format of the profiles written to --profile-out"),
+    ]
+}
+
+pub fn set_profile_output(matches: &ArgMatches) {
+    OUTPUT.get_or_init(|| {
+        matches.get_one::<PathBuf>("profile-out").map(|directory| {
+            (
+                directory.clone(),
+                *matches
+                    .get_one::<ProfileFormat>("profile-format")
+                    .expect("Format has a default"),
+            )
+        })
+    });
+}
+
+pub struct CpuProfile {
+    guard: ProfilerGuard<'static>,
+    file: PathBuf,
+    format: ProfileFormat,
+}
+
+impl CpuProfile {
+    // Only one profiler can sample at a time, so parts are profiled one after another
+    pub fn start(day: &str, run_value: &str) -> Result<Option<Self>> {
+        let Some((directory, format)) = OUTPUT.get().and_then(|output| output.clone()) else {
+            return Ok(None);
+        };
+
+        let extension = match format {
+            ProfileFormat::Flamegraph => "svg",
+            ProfileFormat::Pprof => "pb",
+        };
+        create_dir_all(&directory)?;
+
+        Ok(Some(CpuProfile {
+            guard: ProfilerGuardBuilder::default()
+                .frequency(1000)
+                .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+                .build()?,
+            file: directory.join(format!("{}-{}.{}", day, run_value, extension)),
+            format,
+        }))
+    }
+
+    pub fn finish(self) -> Result<()> {
+        let report = self.guard.report().build()?;
+
+        match self.format {
+            ProfileFormat::Flamegraph => report.flamegraph(File::create(&self.file)?)?,
+            ProfileFormat::Pprof => write(&self.file, report.pprof()?.encode_to_vec())?,
+        }
+
+        println!("Wrote profile to {}", self.file.display());
+        Ok(())
+    }
+}
@@ -1,12 +1,40 @@
 use std::{
     fs::{create_dir_all, read_to_string, write},
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
-pub fn file_to_string(file_name: &PathBuf) -> Result<String, std::io::Error> {
+#[cfg(feature = "mmap")]
+use memmap2::Mmap;
+#[cfg(not(feature = "mmap"))]
+use std::fs::read;
+#[cfg(feature = "mmap")]
+use std::fs::File;
+
+// Only the days parsing bytes read files this way
+#[cfg(feature = "mmap")]
+#[cfg_attr(not(feature = "all-days"), allow(dead_code))]
+pub type FileBytes = Mmap;
+#[cfg(not(feature = "mmap"))]
+#[cfg_attr(not(feature = "all-days"), allow(dead_code))]
+pub type FileBytes = Vec<u8>;
+
+pub fn file_to_string(file_name: &Path) -> Result<String, std::io::Error> {
     read_to_string(file_name)
 }
 
+#[cfg_attr(not(feature = "all-days"), allow(dead_code))]
+pub fn file_to_bytes(file_name: &Path) -> Result<FileBytes, std::io::Error> {
+    #[cfg(feature = "mmap")]
+    {
+        // Safety: input files are not expected to be modified while a problem is running
+        File::open(file_name).and_then(|file| unsafe { Mmap::map(&file) })
+    }
+    #[cfg(not(feature = "mmap"))]
+    {
+        read(file_name)
+    }
+}
+
 pub fn save_string_to_file(output: &str, file_name: &PathBuf) -> Result<(), std::io::Error> {
     file_name
         .parent()
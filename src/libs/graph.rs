@@ -18,13 +18,6 @@ pub const CARDINAL_DIRECTIONS: [CardinalDirection; 4] = [
     CardinalDirection::Up,
 ];
 
-pub const DIAGNALS: [DiagnalDirection; 4] = [
-    DiagnalDirection::UpRight,
-    DiagnalDirection::DownRight,
-    DiagnalDirection::DownLeft,
-    DiagnalDirection::UpLeft,
-];
-
 pub const RADIAL_DIRECTIONS: [PointDirection; 8] = [
     PointDirection::Down,
     PointDirection::DownLeft,
@@ -51,10 +44,6 @@ impl BoundedPoint {
         (max_x, max_y)
     }
 
-    pub fn from_table_index((y, x): (usize, usize), max_x: usize, max_y: usize) -> Self {
-        BoundedPoint { x, y, max_x, max_y }
-    }
-
     pub fn get_from_table<'a, T>(&self, table: &'a Array2<T>) -> Option<&'a T> {
         table.get((self.y, self.x))
     }
@@ -62,51 +51,6 @@ impl BoundedPoint {
     pub fn get_mut_from_table<'a, T>(&self, table: &'a mut Array2<T>) -> Option<&'a mut T> {
         table.get_mut((self.y, self.x))
     }
-
-    pub fn insert_into_table<T>(&self, value: T, table: &mut Array2<T>) {
-        *table.get_mut((self.y, self.x)).expect("position exists") = value;
-    }
-
-    pub fn get_adjacent_wrapping(self, point_direction: impl Into<PointDirection>) -> BoundedPoint {
-        match point_direction.into() {
-            PointDirection::Up => BoundedPoint {
-                y: if self.y > 0 { self.y - 1 } else { self.max_y },
-                ..self
-            },
-            PointDirection::Down => BoundedPoint {
-                y: if self.y < self.max_y { self.y + 1 } else { 0 },
-                ..self
-            },
-            PointDirection::Left => BoundedPoint {
-                x: if self.x > 0 { self.x - 1 } else { self.max_x },
-                ..self
-            },
-            PointDirection::Right => BoundedPoint {
-                x: if self.x < self.max_x { self.x + 1 } else { 0 },
-                ..self
-            },
-            PointDirection::UpRight => BoundedPoint {
-                x: if self.x < self.max_x { self.x + 1 } else { 0 },
-                y: if self.y > 0 { self.y - 1 } else { self.max_y },
-                ..self
-            },
-            PointDirection::UpLeft => BoundedPoint {
-                x: if self.x > 0 { self.x - 1 } else { self.max_x },
-                y: if self.y > 0 { self.y - 1 } else { self.max_y },
-                ..self
-            },
-            PointDirection::DownRight => BoundedPoint {
-                x: if self.x < self.max_x { self.x + 1 } else { 0 },
-                y: if self.y < self.max_y { self.y + 1 } else { 0 },
-                ..self
-            },
-            PointDirection::DownLeft => BoundedPoint {
-                x: if self.x > 0 { self.x - 1 } else { self.max_x },
-                y: if self.y < self.max_y { self.y + 1 } else { 0 },
-                ..self
-            },
-        }
-    }
 }
 
 impl PlanarCoordinate for BoundedPoint {
@@ -315,21 +259,7 @@ impl PlanarCoordinate for BoundedPoint {
     }
 }
 
-#[derive(Debug, Clone)]
-pub enum RotationDegrees {
-    Zero,
-    FortyFive,
-    Ninety,
-    OneHundredThirtyFive,
-    OneHundredEighty,
-    TwoHundredFive,
-    TwoHundredSeventy,
-    ThreeHundredFive,
-}
-
 pub trait Direction {
-    fn get_rotation(&self, other: &Self) -> RotationDegrees;
-
     fn get_opposite(&self) -> Self;
 
     fn get_clockwise(&self) -> Self;
@@ -366,23 +296,6 @@ pub enum PointDirection {
 }
 
 impl Direction for PointDirection {
-    fn get_rotation(&self, other: &PointDirection) -> RotationDegrees {
-        match (self, other) {
-            (p1, p2) if p1 == p2 => RotationDegrees::Zero,
-            (p1, p2) if &p1.get_clockwise() == p2 => RotationDegrees::FortyFive,
-            (p1, p2) if &p1.get_clockwise().get_clockwise() == p2 => RotationDegrees::Ninety,
-            (p1, p2) if &p1.get_opposite().get_counter_clockwise() == p2 => {
-                RotationDegrees::OneHundredThirtyFive
-            }
-            (p1, p2) if &p1.get_opposite() == p2 => RotationDegrees::OneHundredEighty,
-            (p1, p2) if &p1.get_opposite().get_clockwise() == p2 => RotationDegrees::TwoHundredFive,
-            (p1, p2) if &p1.get_counter_clockwise().get_counter_clockwise() == p2 => {
-                RotationDegrees::TwoHundredSeventy
-            }
-            _ => RotationDegrees::ThreeHundredFive,
-        }
-    }
-
     fn get_opposite(&self) -> PointDirection {
         match self {
             PointDirection::Up => PointDirection::Down,
@@ -437,15 +350,6 @@ impl Direction for PointDirection {
 }
 
 impl Direction for CardinalDirection {
-    fn get_rotation(&self, other: &CardinalDirection) -> RotationDegrees {
-        match (self, other) {
-            (p1, p2) if p1 == p2 => RotationDegrees::Zero,
-            (p1, p2) if &p1.get_opposite() == p2 => RotationDegrees::OneHundredEighty,
-            (p1, p2) if &p1.get_counter_clockwise() == p2 => RotationDegrees::TwoHundredSeventy,
-            _ => RotationDegrees::Ninety,
-        }
-    }
-
     fn get_opposite(&self) -> CardinalDirection {
         match self {
             CardinalDirection::Up => CardinalDirection::Down,
@@ -502,15 +406,6 @@ impl From<VerticalDirection> for CardinalDirection {
 }
 
 impl Direction for DiagnalDirection {
-    fn get_rotation(&self, other: &Self) -> RotationDegrees {
-        match (self, other) {
-            (p1, p2) if p1 == p2 => RotationDegrees::Zero,
-            (p1, p2) if &p1.get_opposite() == p2 => RotationDegrees::OneHundredEighty,
-            (p1, p2) if &p1.get_counter_clockwise() == p2 => RotationDegrees::TwoHundredSeventy,
-            _ => RotationDegrees::Ninety,
-        }
-    }
-
     fn get_opposite(&self) -> Self {
         match self {
             DiagnalDirection::UpRight => Self::DownLeft,
@@ -548,38 +443,7 @@ impl Direction for DiagnalDirection {
     }
 }
 
-impl DiagnalDirection {
-    pub fn to_horizontal_and_vertical(self) -> (HorizontalDirection, VerticalDirection) {
-        match self {
-            DiagnalDirection::UpRight => (HorizontalDirection::Right, VerticalDirection::Up),
-            DiagnalDirection::UpLeft => (HorizontalDirection::Left, VerticalDirection::Up),
-            DiagnalDirection::DownRight => (HorizontalDirection::Right, VerticalDirection::Down),
-            DiagnalDirection::DownLeft => (HorizontalDirection::Left, VerticalDirection::Down),
-        }
-    }
-
-    pub fn from_horziontal_and_vertical(
-        horizatonal: &HorizontalDirection,
-        vertical: &VerticalDirection,
-    ) -> DiagnalDirection {
-        match (horizatonal, vertical) {
-            (HorizontalDirection::Left, VerticalDirection::Up) => Self::UpLeft,
-            (HorizontalDirection::Left, VerticalDirection::Down) => Self::DownLeft,
-            (HorizontalDirection::Right, VerticalDirection::Up) => Self::UpRight,
-            (HorizontalDirection::Right, VerticalDirection::Down) => Self::DownRight,
-        }
-    }
-}
-
 impl Direction for HorizontalDirection {
-    fn get_rotation(&self, other: &Self) -> RotationDegrees {
-        if self == other {
-            RotationDegrees::Zero
-        } else {
-            RotationDegrees::Ninety
-        }
-    }
-
     fn get_opposite(&self) -> Self {
         match self {
             HorizontalDirection::Left => HorizontalDirection::Right,
@@ -610,14 +474,6 @@ impl Direction for HorizontalDirection {
 }
 
 impl Direction for VerticalDirection {
-    fn get_rotation(&self, other: &Self) -> RotationDegrees {
-        if self == other {
-            RotationDegrees::Zero
-        } else {
-            RotationDegrees::Ninety
-        }
-    }
-
     fn get_opposite(&self) -> Self {
         match self {
             VerticalDirection::Up => VerticalDirection::Down,
@@ -837,16 +693,13 @@ impl PlanarCoordinate for (usize, usize) {
         vertical_distance: usize,
         vertical_direction: VerticalDirection,
     ) -> Option<Self> {
-        match horizontal_direction {
+        match vertical_direction {
+            VerticalDirection::Up => self.0.checked_sub(vertical_distance),
+            VerticalDirection::Down => Some(self.0 + vertical_distance),
+        }
+        .zip(match horizontal_direction {
             HorizontalDirection::Left => self.1.checked_sub(horizontal_distance),
             HorizontalDirection::Right => Some(self.1 + horizontal_distance),
-        }
-        .and_then(|x| {
-            match vertical_direction {
-                VerticalDirection::Up => self.0.checked_sub(vertical_distance),
-                VerticalDirection::Down => Some(self.0 + vertical_distance),
-            }
-            .map(|y| (y, x))
         })
     }
 
@@ -860,7 +713,7 @@ impl PlanarCoordinate for (usize, usize) {
             PointDirection::UpLeft => self
                 .0
                 .checked_sub(distance)
-                .and_then(|y| self.1.checked_sub(distance).map(|x| (y, x))),
+                .zip(self.1.checked_sub(distance)),
             PointDirection::Down => Some((self.0 + distance, self.1)),
             PointDirection::DownRight => Some((self.0 + distance, self.1 + distance)),
             PointDirection::DownLeft => self
@@ -876,10 +729,7 @@ impl PlanarCoordinate for (usize, usize) {
         match point_direction.into() {
             PointDirection::Up => self.0.checked_sub(1).map(|result| (result, self.1)),
             PointDirection::UpRight => self.0.checked_sub(1).map(|result| (result, self.1 + 1)),
-            PointDirection::UpLeft => self
-                .0
-                .checked_sub(1)
-                .and_then(|y| self.1.checked_sub(1).map(|x| (y, x))),
+            PointDirection::UpLeft => self.0.checked_sub(1).zip(self.1.checked_sub(1)),
             PointDirection::Down => Some((self.0 + 1, self.1)),
             PointDirection::DownRight => Some((self.0 + 1, self.1 + 1)),
             PointDirection::DownLeft => self.1.checked_sub(1).map(|result| (self.0 + 1, result)),
@@ -955,6 +805,7 @@ where
     F: FnMut(&T) -> I,
     I: Iterator<Item = T> + 'a,
 {
+    #[allow(clippy::type_complexity)]
     pub fn get_adjacent<R>(
         get_adjacent: F,
     ) -> BreadthFirstSearchLifecycle<
@@ -0,0 +1,190 @@
+use std::{
+    alloc::{GlobalAlloc, Layout},
+    borrow::Cow,
+    fmt::{self, Display},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use dhat::HeapStats;
+use minitrace::{collector::EventRecord, Event};
+use size::Size;
+
+#[global_allocator]
+static ALLOC: PeakAlloc = PeakAlloc;
+
+static LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE_BLOCKS: AtomicU64 = AtomicU64::new(0);
+static PEAK_BLOCKS: AtomicU64 = AtomicU64::new(0);
+
+// dhat's peaks cover the whole program and cannot be reset, so live bytes and blocks are also
+// tracked here where each span can take its own high water mark
+struct PeakAlloc;
+
+fn grow(bytes: u64, blocks: u64) {
+    PEAK_BYTES.fetch_max(
+        LIVE_BYTES.fetch_add(bytes, Ordering::Relaxed) + bytes,
+        Ordering::Relaxed,
+    );
+    PEAK_BLOCKS.fetch_max(
+        LIVE_BLOCKS.fetch_add(blocks, Ordering::Relaxed) + blocks,
+        Ordering::Relaxed,
+    );
+}
+
+fn shrink(bytes: u64, blocks: u64) {
+    LIVE_BYTES.fetch_sub(bytes, Ordering::Relaxed);
+    LIVE_BLOCKS.fetch_sub(blocks, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for PeakAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = unsafe { dhat::Alloc.alloc(layout) };
+        if !pointer.is_null() {
+            grow(layout.size() as u64, 1);
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = unsafe { dhat::Alloc.alloc_zeroed(layout) };
+        if !pointer.is_null() {
+            grow(layout.size() as u64, 1);
+        }
+        pointer
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = unsafe { dhat::Alloc.realloc(pointer, layout, new_size) };
+        if !new_pointer.is_null() {
+            shrink(layout.size() as u64, 0);
+            grow(new_size as u64, 0);
+        }
+        new_pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        unsafe { dhat::Alloc.dealloc(pointer, layout) };
+        shrink(layout.size() as u64, 1);
+    }
+}
+
+// Records the memory figures of the enclosing span when dropped, so it must be dropped first
+pub struct HeapTracker {
+    start: HeapStats,
+    start_live_bytes: u64,
+    start_live_blocks: u64,
+    outer_peak_bytes: u64,
+    outer_peak_blocks: u64,
+}
+
+impl HeapTracker {
+    pub fn new() -> Self {
+        let start_live_bytes = LIVE_BYTES.load(Ordering::Relaxed);
+        let start_live_blocks = LIVE_BLOCKS.load(Ordering::Relaxed);
+
+        HeapTracker {
+            start: HeapStats::get(),
+            start_live_bytes,
+            start_live_blocks,
+            outer_peak_bytes: PEAK_BYTES.swap(start_live_bytes, Ordering::Relaxed),
+            outer_peak_blocks: PEAK_BLOCKS.swap(start_live_blocks, Ordering::Relaxed),
+        }
+    }
+}
+
+impl Drop for HeapTracker {
+    fn drop(&mut self) {
+        let end = HeapStats::get();
+        // An enclosing span's peak is the larger of its own so far and this one's
+        let peak_bytes = PEAK_BYTES.fetch_max(self.outer_peak_bytes, Ordering::Relaxed);
+        let peak_blocks = PEAK_BLOCKS.fetch_max(self.outer_peak_blocks, Ordering::Relaxed);
+
+        let figures = MemoryFigures {
+            peak_bytes: peak_bytes.saturating_sub(self.start_live_bytes),
+            max_blocks: peak_blocks.saturating_sub(self.start_live_blocks),
+            allocations: end.total_blocks - self.start.total_blocks,
+            allocated_bytes: end.total_bytes - self.start.total_bytes,
+            leaked_bytes: end.curr_bytes.saturating_sub(self.start.curr_bytes) as u64,
+        };
+        Event::add_to_local_parent("memory", || figures.properties());
+    }
+}
+
+// Peaks are measured from the live heap when the span started, and leaked bytes are the ones
+// allocated during the span that are still live when it ends
+#[derive(Clone, Copy, Default)]
+pub struct MemoryFigures {
+    pub peak_bytes: u64,
+    pub max_blocks: u64,
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+    pub leaked_bytes: u64,
+}
+
+impl MemoryFigures {
+    const PROPERTIES: [&'static str; 5] = [
+        "peak_bytes",
+        "max_blocks",
+        "allocations",
+        "allocated_bytes",
+        "leaked_bytes",
+    ];
+
+    fn properties(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        Self::PROPERTIES
+            .into_iter()
+            .zip([
+                self.peak_bytes,
+                self.max_blocks,
+                self.allocations,
+                self.allocated_bytes,
+                self.leaked_bytes,
+            ])
+            .map(|(name, value)| (name.into(), value.to_string().into()))
+            .collect()
+    }
+
+    pub fn from_event(event: &EventRecord) -> Option<Self> {
+        let value = |name: &str| {
+            event
+                .properties
+                .iter()
+                .find(|(property, _)| property == name)
+                .and_then(|(_, value)| value.parse().ok())
+        };
+
+        Some(MemoryFigures {
+            peak_bytes: value("peak_bytes")?,
+            max_blocks: value("max_blocks")?,
+            allocations: value("allocations")?,
+            allocated_bytes: value("allocated_bytes")?,
+            leaked_bytes: value("leaked_bytes")?,
+        })
+    }
+
+    // Combines the figures of spans that ran one after another, like the chunks of a streamed input
+    pub fn merge(self, other: Self) -> Self {
+        MemoryFigures {
+            peak_bytes: self.peak_bytes.max(other.peak_bytes),
+            max_blocks: self.max_blocks.max(other.max_blocks),
+            allocations: self.allocations + other.allocations,
+            allocated_bytes: self.allocated_bytes + other.allocated_bytes,
+            leaked_bytes: self.leaked_bytes + other.leaked_bytes,
+        }
+    }
+}
+
+impl Display for MemoryFigures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "peak: {:>10}, max blocks: {:>8}, allocations: {:>9}, allocated: {:>10}, leaked: {:>10}",
+            Size::from_bytes(self.peak_bytes).to_string(),
+            self.max_blocks,
+            self.allocations,
+            Size::from_bytes(self.allocated_bytes).to_string(),
+            Size::from_bytes(self.leaked_bytes).to_string(),
+        )
+    }
+}
@@ -0,0 +1,240 @@
+use std::{
+    fs::{create_dir_all, read_to_string, write},
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Result;
+use ureq::Agent;
+use url::Url;
+
+pub enum CachePolicy {
+    // The response never changes once it exists, so a cached copy is used without a request
+    Immutable,
+    // The cached copy is revalidated with the server using its ETag or Last-Modified headers
+    Revalidate,
+}
+
+pub struct HttpCache {
+    directory: PathBuf,
+    last_request_file: PathBuf,
+    min_interval: Duration,
+}
+
+impl HttpCache {
+    // Responses are kept apart per partition, while the request interval applies across the root
+    pub fn new(root: PathBuf, partition: Option<&str>, min_interval: Duration) -> Self {
+        let mut directory = root.clone();
+        directory.extend(partition);
+        HttpCache {
+            directory,
+            last_request_file: root.join("last_request"),
+            min_interval,
+        }
+    }
+
+    pub fn get(&self, agent: &Agent, url: &Url, policy: CachePolicy) -> Result<String> {
+        let body_file = self.directory.join(cache_key(url));
+        let headers_file = body_file.with_extension("headers");
+        let cached = body_file
+            .exists()
+            .then(|| read_to_string(&body_file))
+            .transpose()?;
+
+        if let (Some(body), CachePolicy::Immutable) = (&cached, &policy) {
+            return Ok(body.clone());
+        }
+
+        let validators = cached
+            .as_ref()
+            .filter(|_| headers_file.exists())
+            .map(|_| read_to_string(&headers_file))
+            .transpose()?
+            .unwrap_or_default();
+
+        let request = validators
+            .lines()
+            .filter_map(|line| line.split_once(": "))
+            .fold(
+                agent.get(url.as_str()),
+                |request, (header, value)| match header {
+                    "ETag" => request.set("If-None-Match", value),
+                    "Last-Modified" => request.set("If-Modified-Since", value),
+                    _ => request,
+                },
+            );
+        self.throttle()?;
+        let response = request.call()?;
+
+        match (response.status(), cached) {
+            (304, Some(body)) => Ok(body),
+            _ => {
+                let validators = ["ETag", "Last-Modified"]
+                    .into_iter()
+                    .filter_map(|header| {
+                        response
+                            .header(header)
+                            .map(|value| format!("{}: {}\n", header, value))
+                    })
+                    .collect::<String>();
+                let body = response.into_string()?;
+
+                create_dir_all(&self.directory)?;
+                write(&body_file, &body)?;
+                write(&headers_file, validators)?;
+                Ok(body)
+            }
+        }
+    }
+
+    // Waits out the minimum interval since the last request made by any invocation
+    pub fn throttle(&self) -> Result<()> {
+        let last_request_file = &self.last_request_file;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+
+        let wait = last_request_file
+            .exists()
+            .then(|| read_to_string(last_request_file))
+            .transpose()?
+            .and_then(|last_request| last_request.trim().parse::<u64>().ok())
+            .map(Duration::from_millis)
+            .and_then(|last_request| (last_request + self.min_interval).checked_sub(now));
+
+        if let Some(wait) = wait {
+            println!("Waiting {:#?} before the next request", wait);
+            thread::sleep(wait);
+        }
+
+        create_dir_all(last_request_file.parent().expect("Is in the cache root"))?;
+        // Rounded up, since waiting from a truncated time could let the next request in early
+        write(
+            last_request_file,
+            (SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() + 1).to_string(),
+        )
+        .map_err(|e| e.into())
+    }
+}
+
+fn cache_key(url: &Url) -> String {
+    url[url::Position::BeforeHost..]
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+// Reads a request's head and then its body up to the Content-Length, or until the client closes
+#[cfg(test)]
+pub(crate) fn read_request(stream: &mut impl std::io::Read) -> String {
+    let mut request = Vec::new();
+    let mut buffer = [0; 1024];
+    let mut expected_length = None;
+    while expected_length.is_none_or(|length| request.len() < length) {
+        let read = stream.read(&mut buffer).expect("Reads");
+        if read == 0 {
+            break;
+        }
+        request.extend_from_slice(&buffer[..read]);
+
+        if expected_length.is_none() {
+            expected_length = request
+                .windows(4)
+                .position(|bytes| bytes == b"\r\n\r\n")
+                .map(|head_length| {
+                    let head = String::from_utf8_lossy(&request[..head_length]).to_lowercase();
+                    let body_length = head
+                        .lines()
+                        .find_map(|line| line.strip_prefix("content-length:"))
+                        .map_or(0, |length| length.trim().parse().expect("Numeric length"));
+                    head_length + 4 + body_length
+                });
+        }
+    }
+    String::from_utf8(request).expect("Utf8 request")
+}
+
+#[cfg(test)]
+fn serve(responses: Vec<&'static str>) -> (Url, thread::JoinHandle<Vec<String>>) {
+    use std::{io::Write, net::TcpListener};
+
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Binds");
+    let url = Url::parse(&format!(
+        "http://127.0.0.1:{}/2024/day/1",
+        listener.local_addr().expect("Has address").port()
+    ))
+    .expect("Valid url");
+
+    let server = thread::spawn(move || {
+        responses
+            .into_iter()
+            .map(|response| {
+                let (mut stream, _) = listener.accept().expect("Connects");
+                let request = read_request(&mut stream);
+                stream.write_all(response.as_bytes()).expect("Writes");
+                request
+            })
+            .collect()
+    });
+
+    (url, server)
+}
+
+#[test]
+fn revalidates_cached_responses() {
+    let directory = std::env::temp_dir().join(format!("http_cache_{}", std::process::id()));
+    let cache = HttpCache::new(directory.clone(), None, Duration::ZERO);
+    let (url, server) = serve(vec![
+        "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Length: 4\r\nConnection: close\r\n\r\npage",
+        "HTTP/1.1 304 Not Modified\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+    ]);
+    let agent = Agent::new();
+
+    assert_eq!(
+        cache
+            .get(&agent, &url, CachePolicy::Revalidate)
+            .expect("Fetches"),
+        "page"
+    );
+    assert_eq!(
+        cache
+            .get(&agent, &url, CachePolicy::Revalidate)
+            .expect("Revalidates"),
+        "page"
+    );
+    assert_eq!(
+        cache
+            .get(&agent, &url, CachePolicy::Immutable)
+            .expect("Uses the cache"),
+        "page"
+    );
+
+    let requests = server.join().expect("Server finishes");
+    assert!(!requests[0].contains("If-None-Match"));
+    assert!(requests[1].contains("If-None-Match: \"v1\""));
+    std::fs::remove_dir_all(directory).expect("Cleans up");
+}
+
+#[test]
+fn waits_between_requests() {
+    let directory =
+        std::env::temp_dir().join(format!("http_cache_interval_{}", std::process::id()));
+    // Profiles cache apart but still share the interval
+    let caches = ["first", "second"].map(|profile| {
+        HttpCache::new(directory.clone(), Some(profile), Duration::from_millis(200))
+    });
+    let (url, server) = serve(vec![
+        "HTTP/1.1 200 OK\r\nContent-Length: 1\r\nConnection: close\r\n\r\na",
+        "HTTP/1.1 200 OK\r\nContent-Length: 1\r\nConnection: close\r\n\r\nb",
+    ]);
+    let agent = Agent::new();
+
+    let start = SystemTime::now();
+    caches.iter().for_each(|cache| {
+        cache.throttle().expect("Throttles");
+        agent.get(url.as_str()).call().expect("Requests");
+    });
+
+    assert!(start.elapsed().expect("Time passes") >= Duration::from_millis(200));
+    server.join().expect("Server finishes");
+    std::fs::remove_dir_all(directory).expect("Cleans up");
+}
@@ -0,0 +1,26 @@
+// Times the rest of the enclosing block as a phase of the running day, e.g. trace_phase!("build_graph");
+#[macro_export]
+macro_rules! trace_phase {
+    ($name:literal) => {
+        #[cfg(feature = "telemetry")]
+        let _phase = $crate::libs::telemetry::Phase::enter($name);
+    };
+}
+
+// Adds to a named counter reported with the running day, e.g. count!("nodes_expanded", 1);
+#[macro_export]
+macro_rules! count {
+    ($name:literal, $amount:expr) => {
+        #[cfg(feature = "telemetry")]
+        {
+            static COUNTER: $crate::libs::telemetry::Counter =
+                $crate::libs::telemetry::Counter::new($name);
+            COUNTER.add($amount as u64);
+        }
+        // The amount is still evaluated, in case it has side effects
+        #[cfg(not(feature = "telemetry"))]
+        {
+            let _ = $amount;
+        }
+    };
+}
@@ -0,0 +1,113 @@
+use itertools::Itertools;
+use scraper::ElementRef;
+
+// Converts the subset of html used by puzzle descriptions: headings, paragraphs, code blocks,
+// lists, links and emphasis
+pub fn html_to_markdown(element: &ElementRef) -> String {
+    blocks(element).trim().to_string()
+}
+
+fn blocks(element: &ElementRef) -> String {
+    element
+        .children()
+        .map(|node| match ElementRef::wrap(node) {
+            Some(child) => match child.value().name() {
+                "h1" => format!("# {}\n\n", inline(&child).trim()),
+                "h2" => format!("## {}\n\n", inline(&child).trim()),
+                "h3" => format!("### {}\n\n", inline(&child).trim()),
+                "pre" => format!(
+                    "```\n{}\n```\n\n",
+                    child.text().collect::<String>().trim_end_matches('\n')
+                ),
+                "ul" => format!(
+                    "{}\n",
+                    child
+                        .children()
+                        .filter_map(ElementRef::wrap)
+                        .map(|item| format!("- {}\n", inline(&item).trim()))
+                        .collect::<String>()
+                ),
+                _ => format!("{}\n\n", inline(&child).trim()),
+            },
+            None => node
+                .value()
+                .as_text()
+                .map(|text| text.trim().to_string())
+                .filter(|text| !text.is_empty())
+                .map(|text| format!("{}\n\n", text))
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
+fn inline(element: &ElementRef) -> String {
+    element
+        .children()
+        .map(|node| match ElementRef::wrap(node) {
+            Some(child) => match child.value().name() {
+                "code" => {
+                    let code = format!("`{}`", child.text().collect::<String>());
+                    if child
+                        .children()
+                        .filter_map(ElementRef::wrap)
+                        .any(|inner| inner.value().name() == "em")
+                    {
+                        format!("**{}**", code)
+                    } else {
+                        code
+                    }
+                }
+                "em" => format!("*{}*", inline(&child)),
+                "a" => format!(
+                    "[{}]({})",
+                    inline(&child),
+                    child.value().attr("href").unwrap_or_default()
+                ),
+                "br" => "  \n".to_string(),
+                _ => inline(&child),
+            },
+            None => node
+                .value()
+                .as_text()
+                .map(|text| text.split('\n').join(" "))
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
+#[test]
+fn converts_puzzle_descriptions() {
+    use scraper::{Html, Selector};
+
+    let page = Html::parse_document(
+        "<article class=\"day-desc\"><h2>--- Day 1: Historian Hysteria ---</h2>
+        <p>The <em>Chief Historian</em> is missing. See <a href=\"/2024/about\">about</a>.</p>
+        <pre><code>3   4
+4   3
+</code></pre>
+        <ul><li>The <code>3</code> pairs with <code>4</code>.</li><li>Then the next.</li></ul>
+        <p>The total is <code><em>11</em></code>.</p>
+        </article>",
+    );
+    let article = page
+        .select(&Selector::parse("article").expect("Valid selector"))
+        .next()
+        .expect("Article exists");
+
+    assert_eq!(
+        html_to_markdown(&article),
+        "## --- Day 1: Historian Hysteria ---
+
+The *Chief Historian* is missing. See [about](/2024/about).
+
+```
+3   4
+4   3
+```
+
+- The `3` pairs with `4`.
+- Then the next.
+
+The total is **`11`**."
+    );
+}
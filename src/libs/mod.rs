@@ -1,8 +1,27 @@
+// The days' shared parsers and helpers go partly unused when only some days are compiled
+#[cfg_attr(not(feature = "all-days"), allow(dead_code))]
+pub(crate) mod byte_parse;
 pub(crate) mod cli;
+pub(crate) mod config;
 pub(crate) mod file_system;
+#[cfg_attr(not(feature = "all-days"), allow(dead_code))]
 pub(crate) mod graph;
+pub(crate) mod http_cache;
+pub(crate) mod instrument;
+pub(crate) mod markdown;
+#[cfg_attr(not(feature = "all-days"), allow(dead_code))]
 pub(crate) mod parse;
 pub(crate) mod problem;
+pub(crate) mod profile;
+pub(crate) mod sessions;
+#[cfg_attr(not(feature = "all-days"), allow(dead_code))]
+pub(crate) mod stream;
 
+#[cfg(feature = "profile")]
+pub(crate) mod cpu_profile;
+#[cfg(feature = "memory-analysis")]
+pub(crate) mod heap_tracking;
 #[cfg(feature = "telemetry")]
 pub(crate) mod telemetry;
+#[cfg(feature = "telemetry")]
+pub(crate) mod trace_export;
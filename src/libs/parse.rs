@@ -1,6 +1,7 @@
 use std::{
     error::Error,
     fmt::{self, Display},
+    str::FromStr,
 };
 
 use ariadne::{Color, Label, Report, ReportKind, Source};
@@ -17,24 +18,29 @@ use itertools::Itertools;
 use ndarray::Array2;
 use tap::Tap;
 
-pub trait StringParse: Sized {
-    fn parse<'a>() -> impl Parser<'a, &'a str, Self, extra::Err<Rich<'a, char>>>;
+pub trait StringParse<'a>: Sized {
+    fn parse() -> impl Parser<'a, &'a str, Self, extra::Err<Rich<'a, char>>>;
 }
 
-pub struct StringParser<T: StringParse>(pub T);
+// Names the parsed input for any lifetime of the file buffer, so inputs can borrow from it
+pub trait StringInput {
+    type Borrowed<'a>: StringParse<'a>;
+}
+
+pub struct StringParser<T>(pub T);
 
-impl<T> TryFrom<String> for StringParser<T>
+impl<'a, T> TryFrom<&'a str> for StringParser<T>
 where
-    T: StringParse,
+    T: StringParse<'a>,
 {
     type Error = anyhow::Error;
 
-    fn try_from(value: String) -> Result<Self, Self::Error> {
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
         T::parse()
             .map(StringParser)
-            .parse(&value)
+            .parse(value)
             .into_result()
-            .map_err(|e| ParseError::new(&value, e).into())
+            .map_err(|e| ParseError::new(value, e).into())
     }
 }
 
@@ -69,6 +75,19 @@ pub fn parse_isize_with_radix<'a>(
         })
 }
 
+// Parses any integer type that can be read from a string, from u8 up to dashu's big integers
+pub fn parse_integer<'a, N>() -> impl Parser<'a, &'a str, N, extra::Err<Rich<'a, char>>> + Clone
+where
+    N: FromStr,
+    N::Err: Display,
+{
+    just('-')
+        .or_not()
+        .then(text::int(10))
+        .to_slice()
+        .try_map(|number: &str, span| number.parse().map_err(|op| Rich::custom(span, op)))
+}
+
 pub fn parse_alphanumeric<
     'a,
     I: ValueInput<'a> + StrInput<'a, C>,
@@ -139,12 +158,118 @@ pub fn parse_table2<'a, T>(
 pub fn parse_between_blank_lines<'a, T>(
     chunk_parser: impl Parser<'a, &'a str, T, extra::Err<Rich<'a, char>>>,
 ) -> impl Parser<'a, &'a str, Vec<T>, extra::Err<Rich<'a, char>>> {
-    let blank_line = newline().repeated().exactly(2).ignored();
+    let blank_line = newline()
+        .then(text::inline_whitespace())
+        .then(newline())
+        .then(text::inline_whitespace().then(newline()).repeated())
+        .ignored();
     chunk_parser
         .separated_by(blank_line)
         .allow_trailing()
         .collect::<Vec<_>>()
-        .then_ignore(newline().repeated())
+        .then_ignore(text::whitespace())
+}
+
+pub fn parse_coordinate<'a, T>(
+    item_parser: impl Parser<'a, &'a str, T, extra::Err<Rich<'a, char>>> + Clone,
+) -> impl Parser<'a, &'a str, (T, T), extra::Err<Rich<'a, char>>> {
+    item_parser
+        .clone()
+        .then_ignore(just(',').padded_by(text::inline_whitespace()))
+        .then(item_parser)
+}
+
+pub fn parse_key_value<'a, K, V>(
+    key_parser: impl Parser<'a, &'a str, K, extra::Err<Rich<'a, char>>>,
+    value_parser: impl Parser<'a, &'a str, V, extra::Err<Rich<'a, char>>>,
+) -> impl Parser<'a, &'a str, (K, V), extra::Err<Rich<'a, char>>> {
+    key_parser
+        .then_ignore(just(':'))
+        .then_ignore(text::inline_whitespace())
+        .then(value_parser)
+}
+
+pub fn parse_key_values<'a, K, V>(
+    key_parser: impl Parser<'a, &'a str, K, extra::Err<Rich<'a, char>>>,
+    value_parser: impl Parser<'a, &'a str, V, extra::Err<Rich<'a, char>>>,
+) -> impl Parser<'a, &'a str, Vec<(K, V)>, extra::Err<Rich<'a, char>>> {
+    parse_lines(parse_key_value(key_parser, value_parser))
+}
+
+pub fn parse_comma_separated<'a, T>(
+    item_parser: impl Parser<'a, &'a str, T, extra::Err<Rich<'a, char>>>,
+) -> impl Parser<'a, &'a str, Vec<T>, extra::Err<Rich<'a, char>>> {
+    item_parser
+        .separated_by(just(',').padded_by(text::inline_whitespace()))
+        .allow_trailing()
+        .at_least(1)
+        .collect()
+}
+
+pub fn parse_space_separated<'a, T>(
+    item_parser: impl Parser<'a, &'a str, T, extra::Err<Rich<'a, char>>>,
+) -> impl Parser<'a, &'a str, Vec<T>, extra::Err<Rich<'a, char>>> {
+    item_parser
+        .separated_by(text::inline_whitespace().at_least(1))
+        .allow_trailing()
+        .at_least(1)
+        .collect()
+}
+
+pub struct MarkedGrid<T, M> {
+    pub grid: Array2<T>,
+    pub markers: Vec<(M, (usize, usize))>,
+}
+
+impl<T, M: PartialEq> MarkedGrid<T, M> {
+    pub fn find(&self, marker: &M) -> Option<(usize, usize)> {
+        self.find_all(marker).next()
+    }
+
+    pub fn find_all<'b>(&'b self, marker: &'b M) -> impl Iterator<Item = (usize, usize)> + 'b {
+        self.markers
+            .iter()
+            .filter(move |(current, _)| current == marker)
+            .map(|(_, index)| *index)
+    }
+}
+
+// Each cell parses to its grid value and optionally a marker, such as a start point, which is
+// recorded with its (row, column) index
+pub fn parse_grid_with_markers<'a, T, M>(
+    cell_parser: impl Parser<'a, &'a str, (T, Option<M>), extra::Err<Rich<'a, char>>>,
+) -> impl Parser<'a, &'a str, MarkedGrid<T, M>, extra::Err<Rich<'a, char>>> {
+    parse_table(cell_parser).try_map(|rows, span| {
+        let shape = (rows.len(), rows.first().map_or(0, |row| row.len()));
+        if let Some(row_index) = rows.iter().position(|row| row.len() != shape.1) {
+            return Err(Rich::custom(
+                span,
+                format!(
+                    "Row {} has {} cells where the first row has {}",
+                    row_index + 1,
+                    rows[row_index].len(),
+                    shape.1
+                ),
+            ));
+        }
+
+        let (cells, markers) = rows.into_iter().enumerate().fold(
+            (Vec::new(), Vec::new()),
+            |(mut cells, mut markers), (row_index, row)| {
+                row.into_iter()
+                    .enumerate()
+                    .for_each(|(column_index, (cell, marker))| {
+                        cells.push(cell);
+                        markers.extend(marker.map(|marker| (marker, (row_index, column_index))));
+                    });
+                (cells, markers)
+            },
+        );
+
+        Array2::from_shape_vec(shape, cells)
+            .map(|grid| MarkedGrid { grid, markers })
+            .map_err(|op| Rich::custom(span, op))
+    })
 }
 
 pub trait ParserExt<'a, I: Input<'a>, O, E: ParserExtra<'a, I> = extra::Default>:
@@ -176,6 +301,33 @@ impl ParseError {
             error: combine_parse_errors(file, &errors),
         }
     }
+
+    pub fn at_line<'a>(line_number: usize, line: &'a str, errors: Vec<Rich<'a, char>>) -> Self {
+        ParseError {
+            error: format!(
+                "Line {}:\n{}",
+                line_number,
+                combine_parse_errors(line, &errors)
+            ),
+        }
+    }
+
+    pub fn blank_line(line_number: usize) -> Self {
+        ParseError {
+            error: format!("Line {}:\nUnexpected blank line", line_number),
+        }
+    }
+
+    pub fn from_bytes<'a>(file: &'a [u8], errors: Vec<Rich<'a, u8>>) -> Self {
+        let file = String::from_utf8_lossy(file);
+        let errors = errors
+            .into_iter()
+            .map(|error| error.map_token(char::from))
+            .collect::<Vec<_>>();
+        ParseError {
+            error: combine_parse_errors(&file, &errors),
+        }
+    }
 }
 
 impl Display for ParseError {
@@ -216,3 +368,107 @@ pub fn format_parse_error<'a>(source: &'a str, error: &Rich<'a, char>) -> String
         .expect("Worked");
     std::str::from_utf8(&buf[..]).unwrap().to_string()
 }
+
+#[test]
+fn parses_integers_of_any_width() {
+    assert_eq!(parse_integer::<u8>().parse("255").into_result(), Ok(255));
+    assert!(parse_integer::<u8>().parse("256").has_errors());
+    assert!(parse_integer::<u32>().parse("-1").has_errors());
+    assert_eq!(parse_integer::<i16>().parse("-300").into_result(), Ok(-300));
+    assert_eq!(
+        parse_integer::<u128>()
+            .parse("340282366920938463463374607431768211455")
+            .into_result(),
+        Ok(u128::MAX)
+    );
+    assert_eq!(
+        parse_integer::<dashu::integer::IBig>()
+            .parse("-340282366920938463463374607431768211456")
+            .into_result(),
+        Ok(-dashu::integer::IBig::from(u128::MAX) - 1)
+    );
+}
+
+#[test]
+fn parses_coordinates_and_lists() {
+    assert_eq!(
+        parse_lines(parse_coordinate(parse_integer::<isize>()))
+            .parse("1,2\n-3, 4")
+            .into_result(),
+        Ok(vec![(1, 2), (-3, 4)])
+    );
+    assert_eq!(
+        parse_comma_separated(parse_usize())
+            .parse("1, 2,3,")
+            .into_result(),
+        Ok(vec![1, 2, 3])
+    );
+    assert_eq!(
+        parse_space_separated(parse_usize())
+            .parse("1  2 3 ")
+            .into_result(),
+        Ok(vec![1, 2, 3])
+    );
+    assert!(parse_comma_separated(parse_usize()).parse("").has_errors());
+}
+
+#[test]
+fn parses_key_value_blocks() {
+    let key = any()
+        .filter(|c: &char| *c != ':' && *c != '\n')
+        .repeated()
+        .at_least(1)
+        .to_slice();
+    assert_eq!(
+        parse_key_values(key, parse_usize())
+            .parse("Register A: 729\nRegister B:0")
+            .into_result(),
+        Ok(vec![("Register A", 729), ("Register B", 0)])
+    );
+}
+
+#[test]
+fn parses_sections_with_crlf() {
+    assert_eq!(
+        parse_between_blank_lines(parse_lines(parse_usize()))
+            .parse("1\r\n2\r\n\r\n3\r\n  \r\n\r\n4\r\n")
+            .into_result(),
+        Ok(vec![vec![1, 2], vec![3], vec![4]])
+    );
+}
+
+#[test]
+fn parses_grid_with_markers() {
+    #[derive(Debug, Clone, PartialEq)]
+    enum Marker {
+        Start,
+        Robot,
+    }
+
+    let cell = just('S')
+        .to((true, Some(Marker::Start)))
+        .or(just('@').to((true, Some(Marker::Robot))))
+        .or(just('.').to((true, None)))
+        .or(just('#').to((false, None)));
+    let marked = parse_grid_with_markers(cell.clone())
+        .parse("#S.\n@.@")
+        .into_result()
+        .expect("Parses");
+
+    assert_eq!(
+        marked.grid,
+        Array2::from_shape_vec((2, 3), vec![false, true, true, true, true, true]).unwrap()
+    );
+    assert_eq!(marked.find(&Marker::Start), Some((0, 1)));
+    assert_eq!(
+        marked.find_all(&Marker::Robot).collect::<Vec<_>>(),
+        vec![(1, 0), (1, 2)]
+    );
+    assert!(parse_grid_with_markers(cell.clone())
+        .parse("#S.\n@\n.@")
+        .has_errors());
+    // Rows of different widths are rejected even when the cells would fill a grid
+    assert!(parse_grid_with_markers(cell)
+        .parse("#S\n@\n.@.")
+        .has_errors());
+}
@@ -1,11 +1,54 @@
 use std::fmt::{self, Display};
 
-#[derive(PartialEq, Clone)]
+use dashu::integer::{IBig, UBig};
+use itertools::Itertools;
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+#[derive(Debug, Clone)]
 pub enum ProblemResult {
     Isize(isize),
     Usize(usize),
     U32(u32),
+    U64(u64),
+    I64(i64),
+    U128(u128),
+    I128(i128),
+    BigInt(IBig),
     String(String),
+    Grid(Vec<String>),
+}
+
+impl ProblemResult {
+    fn as_big_int(&self) -> Option<IBig> {
+        match self {
+            ProblemResult::Isize(val) => Some(IBig::from(*val)),
+            ProblemResult::Usize(val) => Some(IBig::from(*val)),
+            ProblemResult::U32(val) => Some(IBig::from(*val)),
+            ProblemResult::U64(val) => Some(IBig::from(*val)),
+            ProblemResult::I64(val) => Some(IBig::from(*val)),
+            ProblemResult::U128(val) => Some(IBig::from(*val)),
+            ProblemResult::I128(val) => Some(IBig::from(*val)),
+            ProblemResult::BigInt(val) => Some(val.clone()),
+            ProblemResult::String(_) | ProblemResult::Grid(_) => None,
+        }
+    }
+}
+
+// Numbers compare by value regardless of width, strings and grids only with their own kind
+impl PartialEq for ProblemResult {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ProblemResult::String(left), ProblemResult::String(right)) => left == right,
+            (ProblemResult::Grid(left), ProblemResult::Grid(right)) => left == right,
+            _ => self
+                .as_big_int()
+                .zip(other.as_big_int())
+                .is_some_and(|(left, right)| left == right),
+        }
+    }
 }
 
 impl Display for ProblemResult {
@@ -14,11 +57,97 @@ impl Display for ProblemResult {
             ProblemResult::Isize(val) => val.fmt(f),
             ProblemResult::Usize(val) => val.fmt(f),
             ProblemResult::U32(val) => val.fmt(f),
+            ProblemResult::U64(val) => val.fmt(f),
+            ProblemResult::I64(val) => val.fmt(f),
+            ProblemResult::U128(val) => val.fmt(f),
+            ProblemResult::I128(val) => val.fmt(f),
+            ProblemResult::BigInt(val) => val.fmt(f),
             ProblemResult::String(val) => val.fmt(f),
+            ProblemResult::Grid(val) => val.iter().join("\n").fmt(f),
+        }
+    }
+}
+
+// Numbers that don't fit in a json number are written as strings
+impl Serialize for ProblemResult {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ProblemResult::String(val) => serializer.serialize_str(val),
+            ProblemResult::Grid(val) => serializer.collect_seq(val),
+            number => {
+                let value = number.as_big_int().expect("Only numbers remain");
+                if let Ok(value) = u64::try_from(&value) {
+                    serializer.serialize_u64(value)
+                } else if let Ok(value) = i64::try_from(&value) {
+                    serializer.serialize_i64(value)
+                } else {
+                    serializer.serialize_str(&value.to_string())
+                }
+            }
         }
     }
 }
 
+impl<'de> Deserialize<'de> for ProblemResult {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ProblemResultVisitor)
+    }
+}
+
+struct ProblemResultVisitor;
+
+impl<'de> Visitor<'de> for ProblemResultVisitor {
+    type Value = ProblemResult;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an integer, a string or a list of grid lines")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(ProblemResult::U64(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(ProblemResult::I64(v))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        Ok(ProblemResult::U128(v))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        Ok(ProblemResult::I128(v))
+    }
+
+    // Reverses the serializer writing numbers too wide for json as strings
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(match v.parse::<IBig>() {
+            Ok(value)
+                if value.to_string() == v
+                    && u64::try_from(&value).is_err()
+                    && i64::try_from(&value).is_err() =>
+            {
+                ProblemResult::BigInt(value)
+            }
+            _ => ProblemResult::String(v.to_string()),
+        })
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut lines = Vec::new();
+        while let Some(line) = seq.next_element::<String>()? {
+            lines.push(line);
+        }
+        Ok(ProblemResult::Grid(lines))
+    }
+}
+
 impl From<isize> for ProblemResult {
     fn from(item: isize) -> Self {
         ProblemResult::Isize(item)
@@ -43,8 +172,102 @@ impl From<u32> for ProblemResult {
     }
 }
 
+impl From<u64> for ProblemResult {
+    fn from(value: u64) -> Self {
+        ProblemResult::U64(value)
+    }
+}
+
+impl From<i64> for ProblemResult {
+    fn from(value: i64) -> Self {
+        ProblemResult::I64(value)
+    }
+}
+
+impl From<u128> for ProblemResult {
+    fn from(value: u128) -> Self {
+        ProblemResult::U128(value)
+    }
+}
+
+impl From<i128> for ProblemResult {
+    fn from(value: i128) -> Self {
+        ProblemResult::I128(value)
+    }
+}
+
+impl From<IBig> for ProblemResult {
+    fn from(value: IBig) -> Self {
+        ProblemResult::BigInt(value)
+    }
+}
+
+impl From<UBig> for ProblemResult {
+    fn from(value: UBig) -> Self {
+        ProblemResult::BigInt(value.into())
+    }
+}
+
+impl From<Vec<String>> for ProblemResult {
+    fn from(value: Vec<String>) -> Self {
+        ProblemResult::Grid(value)
+    }
+}
+
 pub trait Problem<A> {
     type Output: Into<ProblemResult> + Clone;
 
     fn run(self, arguments: &A) -> Self::Output;
 }
+
+#[test]
+fn numbers_compare_across_widths() {
+    assert_eq!(ProblemResult::Usize(5), ProblemResult::Isize(5));
+    assert_eq!(ProblemResult::U32(5), ProblemResult::BigInt(IBig::from(5)));
+    assert_eq!(
+        ProblemResult::U128(u128::MAX),
+        ProblemResult::BigInt(IBig::from(u128::MAX))
+    );
+    assert_ne!(ProblemResult::Isize(-5), ProblemResult::Usize(5));
+    assert_ne!(
+        ProblemResult::Usize(5),
+        ProblemResult::String("five".to_string())
+    );
+    assert_ne!(
+        ProblemResult::Usize(5),
+        ProblemResult::String("5".to_string())
+    );
+}
+
+#[test]
+fn grid_renders_as_lines_but_only_equals_grids() {
+    let grid = ProblemResult::Grid(vec!["#.".to_string(), ".#".to_string()]);
+    assert_eq!(grid.to_string(), "#.\n.#");
+    assert_ne!(grid, ProblemResult::String("#.\n.#".to_string()));
+    assert_eq!(
+        grid,
+        ProblemResult::Grid(vec!["#.".to_string(), ".#".to_string()])
+    );
+}
+
+#[test]
+fn round_trips_through_json() {
+    [
+        ProblemResult::Usize(11),
+        ProblemResult::Isize(-3),
+        ProblemResult::I128(i128::MIN),
+        ProblemResult::BigInt(IBig::from(u128::MAX) * IBig::from(u128::MAX)),
+        ProblemResult::String("co,de,ka,ta".to_string()),
+        ProblemResult::Grid(vec!["O.".to_string(), ".O".to_string()]),
+    ]
+    .into_iter()
+    .for_each(|result| {
+        let json = serde_json::to_string(&result).expect("Serializes");
+        let parsed = serde_json::from_str::<ProblemResult>(&json).expect("Deserializes");
+        assert_eq!(result, parsed);
+    });
+    ["\"0123\"", "\"42\""].into_iter().for_each(|json| {
+        let parsed = serde_json::from_str::<ProblemResult>(json).expect("Deserializes");
+        assert!(matches!(parsed, ProblemResult::String(_)));
+    });
+}
@@ -0,0 +1,36 @@
+use std::{path::PathBuf, sync::OnceLock};
+
+use clap::{Arg, ArgMatches};
+use tap::Tap;
+
+static PROFILE: OnceLock<Option<String>> = OnceLock::new();
+
+pub fn profile_arg() -> Arg {
+    Arg::new("profile")
+        .long("profile")
+        .env("AOC_PROFILE")
+        .global(true)
+        .help("The named profile whose session token and inputs are used, for sharing a machine between accounts")
+}
+
+pub fn set_profile(matches: &ArgMatches) {
+    PROFILE.get_or_init(|| matches.get_one::<String>("profile").cloned());
+}
+
+pub fn profile() -> Option<&'static str> {
+    PROFILE.get().and_then(|profile| profile.as_deref())
+}
+
+pub fn profile_name() -> &'static str {
+    profile().unwrap_or("default")
+}
+
+// Inputs differ between accounts so each profile keeps its own, while samples are shared in input/dayNN
+pub fn input_directory(day_name: &str) -> PathBuf {
+    PathBuf::from("input").tap_mut(|path| {
+        if let Some(profile) = profile() {
+            path.push(profile)
+        }
+        path.push(day_name)
+    })
+}
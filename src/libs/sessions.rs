@@ -0,0 +1,111 @@
+use std::{
+    env,
+    fs::{create_dir_all, read_to_string, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Result};
+use tap::Tap;
+
+// Follows the XDG base directory spec, falling back to ~/.config
+pub fn default_sessions_file() -> Result<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|config| config.tap_mut(|path| path.push("adventofcode2024/sessions")))
+        .ok_or_else(|| anyhow!("Could not find a config directory, pass --sessions-file"))
+}
+
+// Each line of the file is a profile name and its token separated by a tab
+pub fn read_session(file: &Path, profile: &str) -> Result<Option<String>> {
+    if !file.exists() {
+        return Ok(None);
+    }
+
+    Ok(read_to_string(file)?
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .find(|(name, _)| *name == profile)
+        .map(|(_, token)| token.to_string()))
+}
+
+pub fn save_session(file: &Path, profile: &str, token: &str) -> Result<()> {
+    let sessions = if file.exists() {
+        read_to_string(file)?
+    } else {
+        String::new()
+    };
+
+    let sessions = sessions
+        .lines()
+        .filter(|line| line.split_once('\t').map(|(name, _)| name) != Some(profile))
+        .map(|line| format!("{}\n", line))
+        .chain([format!("{}\t{}\n", profile, token)])
+        .collect::<String>();
+
+    if let Some(directory) = file.parent() {
+        create_dir_all(directory)?;
+    }
+
+    open_private(file)?
+        .write_all(sessions.as_bytes())
+        .map_err(|e| e.into())
+}
+
+// The token grants access to the account so only the owner may read it
+#[cfg(unix)]
+fn open_private(file: &Path) -> io::Result<File> {
+    use std::{
+        fs::{set_permissions, Permissions},
+        os::unix::fs::{OpenOptionsExt, PermissionsExt},
+    };
+
+    let output = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(file)?;
+    set_permissions(file, Permissions::from_mode(0o600))?;
+    Ok(output)
+}
+
+// Elsewhere the file keeps the permissions of the directory it is in
+#[cfg(not(unix))]
+fn open_private(file: &Path) -> io::Result<File> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(file)
+}
+
+#[test]
+fn saves_sessions_per_profile() {
+    let file = env::temp_dir()
+        .join(format!("sessions_{}", std::process::id()))
+        .join("sessions");
+
+    save_session(&file, "default", "abc").expect("Saves");
+    save_session(&file, "alice", "def").expect("Saves");
+    save_session(&file, "default", "ghi").expect("Replaces");
+
+    assert_eq!(
+        read_session(&file, "default").expect("Reads"),
+        Some("ghi".to_string())
+    );
+    assert_eq!(
+        read_session(&file, "alice").expect("Reads"),
+        Some("def".to_string())
+    );
+    assert_eq!(read_session(&file, "bob").expect("Reads"), None);
+    #[cfg(unix)]
+    assert_eq!(
+        std::os::unix::fs::PermissionsExt::mode(&file.metadata().expect("Exists").permissions())
+            & 0o777,
+        0o600
+    );
+
+    std::fs::remove_dir_all(file.parent().expect("Has directory")).expect("Cleans up");
+}
@@ -0,0 +1,150 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    marker::PhantomData,
+    path::Path,
+};
+
+use anyhow::Result;
+use chumsky::{primitive::end, Parser};
+use itertools::Itertools;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use super::{
+    cli::ProblemFile,
+    parse::{ParseError, StringParse},
+    problem::ProblemResult,
+};
+
+#[cfg(feature = "telemetry")]
+use super::telemetry::RunPartTelemetry;
+
+const CHUNK_LINES: usize = 4096;
+
+// A problem over independent lines which is folded one line at a time instead of parsed up front
+pub trait LineProblem<A>: Send + Sized
+where
+    Self: for<'a> StringParse<'a>,
+{
+    type Accumulator: Send;
+    type Output: Into<ProblemResult> + Clone;
+
+    const PARALLEL: bool = false;
+
+    fn start(arguments: &A) -> Self::Accumulator;
+
+    fn fold(accumulator: Self::Accumulator, line: Self, arguments: &A) -> Self::Accumulator;
+
+    fn reduce(
+        left: Self::Accumulator,
+        right: Self::Accumulator,
+        arguments: &A,
+    ) -> Self::Accumulator;
+
+    fn finish(accumulator: Self::Accumulator, arguments: &A) -> Self::Output;
+}
+
+// Marks a problem whose input file is streamed line by line into a LineProblem
+pub struct Lines<T>(PhantomData<T>);
+
+impl<T, A> ProblemFile<A> for Lines<T>
+where
+    T: LineProblem<A>,
+    A: Sync,
+{
+    type Output = T::Output;
+
+    fn run_file(file: &Path, arguments: &A) -> Result<ProblemResult> {
+        let lines = numbered_lines(BufReader::new(File::open(file)?));
+
+        let accumulator = lines.chunks(CHUNK_LINES).into_iter().try_fold(
+            T::start(arguments),
+            |accumulator, chunk| {
+                let chunk = chunk.collect::<Result<Vec<_>>>()?;
+
+                let parsed = {
+                    #[cfg(feature = "telemetry")]
+                    let _parse = RunPartTelemetry::time_parse();
+                    parse_chunk::<T>(&chunk, T::PARALLEL)
+                }?;
+
+                #[cfg(feature = "telemetry")]
+                let _run = RunPartTelemetry::time_run();
+                Ok::<_, anyhow::Error>(if T::PARALLEL {
+                    let chunk_result = parsed
+                        .into_par_iter()
+                        .fold(
+                            || T::start(arguments),
+                            |accumulator, line| T::fold(accumulator, line, arguments),
+                        )
+                        .reduce(
+                            || T::start(arguments),
+                            |left, right| T::reduce(left, right, arguments),
+                        );
+                    T::reduce(accumulator, chunk_result, arguments)
+                } else {
+                    parsed.into_iter().fold(accumulator, |accumulator, line| {
+                        T::fold(accumulator, line, arguments)
+                    })
+                })
+            },
+        )?;
+
+        #[cfg(feature = "telemetry")]
+        let _run = RunPartTelemetry::time_run();
+        Ok(T::finish(accumulator, arguments).into())
+    }
+}
+
+// Blank lines are only allowed at the end of the input, like the parsers over the whole file
+fn numbered_lines(reader: impl BufRead) -> impl Iterator<Item = Result<(usize, String)>> {
+    let mut blank_line = None;
+    reader
+        .lines()
+        .enumerate()
+        .filter_map(move |(index, line)| match line {
+            Ok(line) if line.is_empty() => {
+                blank_line.get_or_insert(index + 1);
+                None
+            }
+            Ok(line) => Some(match blank_line {
+                Some(blank_line) => Err(ParseError::blank_line(blank_line).into()),
+                None => Ok((index + 1, line)),
+            }),
+            Err(e) => Some(Err(e.into())),
+        })
+}
+
+fn parse_chunk<T>(chunk: &[(usize, String)], parallel: bool) -> Result<Vec<T>>
+where
+    T: for<'a> StringParse<'a> + Send,
+{
+    let parse_line = |(line_number, line): &(usize, String)| {
+        T::parse()
+            .then_ignore(end())
+            .parse(line.as_str())
+            .into_result()
+            .map_err(|e| ParseError::at_line(*line_number, line, e).into())
+    };
+
+    if parallel {
+        chunk.into_par_iter().map(parse_line).collect()
+    } else {
+        chunk.iter().map(parse_line).collect()
+    }
+}
+
+#[test]
+fn skips_only_trailing_blank_lines() {
+    let lines = |input: &str| {
+        numbered_lines(input.as_bytes())
+            .map(|line| line.map(|(number, _)| number))
+            .collect::<Result<Vec<_>>>()
+    };
+
+    assert_eq!(lines("1\n2\n\n\n").expect("Trailing blanks"), vec![1, 2]);
+    assert_eq!(
+        lines("1\n\n2\n").map_err(|e| e.to_string()),
+        Err("Line 2:\nUnexpected blank line".to_string())
+    );
+}
@@ -1,48 +1,90 @@
+use clap::ArgMatches;
 use itertools::Itertools;
 use minitrace::{
-    collector::{Config, Reporter, SpanContext},
+    collector::{Config, Reporter, SpanContext, SpanRecord},
     local::{LocalParentGuard, LocalSpan},
-    Span,
+    Event, Span,
+};
+use std::{
+    borrow::Cow,
+    env,
+    io::{stdout, IsTerminal},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, Once,
+    },
+    time::Duration,
 };
-use std::{borrow::Cow, time::Duration};
 
-#[cfg(feature = "memory-analysis")]
-use dhat::Profiler;
-#[cfg(feature = "memory-analysis")]
-use minitrace::Event;
-#[cfg(feature = "memory-analysis")]
-use size::Size;
+use super::{
+    cli::DEFAULT_BUDGET,
+    problem::ProblemResult,
+    trace_export::{TraceExport, TraceFormat},
+};
 
 #[cfg(feature = "memory-analysis")]
-#[global_allocator]
-static ALLOC: dhat::Alloc = dhat::Alloc;
+use super::heap_tracking::{HeapTracker, MemoryFigures};
+#[cfg(feature = "memory-analysis")]
+use dhat::Profiler;
 
 pub struct Telemetry {
+    collector: Arc<Mutex<DayCollector>>,
     #[cfg(feature = "memory-analysis")]
     _memory_profiler: Profiler,
 }
 
 impl Telemetry {
-    pub fn init_telemetry() -> Self {
+    pub fn init_telemetry(matches: &ArgMatches) -> Self {
         #[cfg(feature = "memory-analysis")]
         let profiler = Profiler::builder().testing().build();
+        let trace = matches.get_one::<PathBuf>("trace-out").map(|file| {
+            TraceExport::new(
+                file.clone(),
+                *matches
+                    .get_one::<TraceFormat>("trace-format")
+                    .expect("Format has a default"),
+            )
+        });
+        let collector = Arc::new(Mutex::new(DayCollector::new()));
         minitrace::set_reporter(
             DayReporter {
-                collector: DayCollector::new(),
+                collector: collector.clone(),
+                trace,
             },
             Config::default(),
         );
         #[cfg(not(feature = "memory-analysis"))]
         {
-            Telemetry {}
+            Telemetry { collector }
         }
         #[cfg(feature = "memory-analysis")]
         {
             Telemetry {
+                collector,
                 _memory_profiler: profiler,
             }
         }
     }
+
+    // Prints every part run so far, so main decides where the report goes instead of it
+    // appearing whenever the reporter happens to be dropped
+    pub fn report(&self) {
+        minitrace::flush();
+        let report = self
+            .collector
+            .lock()
+            .expect("Collector is not poisoned")
+            .report(color_enabled());
+        if !report.is_empty() {
+            print!("{}", report);
+        }
+    }
+}
+
+// Follows https://no-color.org, and leaves out escape codes when the output is piped
+fn color_enabled() -> bool {
+    env::var_os("NO_COLOR").is_none_or(|no_color| no_color.is_empty()) && stdout().is_terminal()
 }
 
 impl Drop for Telemetry {
@@ -60,9 +102,20 @@ pub struct RunPartTelemetry {
 }
 
 impl RunPartTelemetry {
-    pub fn new(day: &'static str, run_value: &'static str) -> Self {
+    pub fn new(day: &'static str, run_value: &str, budget: Option<Duration>) -> Self {
+        COUNTERS
+            .lock()
+            .expect("Counters are not poisoned")
+            .iter()
+            .for_each(|counter| counter.total.store(0, Ordering::Relaxed));
         let root = Span::root("run_part_total", SpanContext::random())
-            .with_properties(|| [("day", day), ("run_value", run_value)]);
+            .with_properties(|| {
+                [
+                    ("day", day.to_string()),
+                    ("run_value", run_value.to_string()),
+                ]
+            })
+            .with_properties(|| budget.map(|budget| ("budget_ns", budget.as_nanos().to_string())));
         #[cfg(not(feature = "memory-analysis"))]
         {
             RunPartTelemetry {
@@ -80,64 +133,128 @@ impl RunPartTelemetry {
         }
     }
 
-    pub fn time_parse(&self) -> LocalSpan {
-        LocalSpan::enter_with_local_parent("parse_input")
+    pub fn record_answer(&self, answer: &ProblemResult) {
+        Event::add_to_parent("answer", &self._root, || {
+            [("answer".into(), answer.to_string().into())]
+        });
+    }
+
+    pub fn time_parse() -> Phase {
+        Phase::enter("parse_input")
     }
 
-    pub fn time_run(&self) -> LocalSpan {
-        LocalSpan::enter_with_local_parent("run_time")
+    pub fn time_run() -> Phase {
+        Phase::enter("run_time")
     }
 }
 
-#[cfg(feature = "memory-analysis")]
-struct HeapTracker {
-    start_bytes: u64,
+// A span inside a part, which also tracks its own memory figures with memory-analysis
+pub struct Phase {
+    #[cfg(feature = "memory-analysis")]
+    _heap_tracker: HeapTracker,
+    _span: LocalSpan,
 }
 
-#[cfg(feature = "memory-analysis")]
-impl HeapTracker {
-    fn new() -> Self {
-        HeapTracker {
-            start_bytes: dhat::HeapStats::get().total_bytes,
+impl Phase {
+    pub fn enter(name: &'static str) -> Self {
+        let span = LocalSpan::enter_with_local_parent(name);
+        #[cfg(not(feature = "memory-analysis"))]
+        {
+            Phase { _span: span }
+        }
+        #[cfg(feature = "memory-analysis")]
+        {
+            Phase {
+                _heap_tracker: HeapTracker::new(),
+                _span: span,
+            }
         }
     }
 }
 
-#[cfg(feature = "memory-analysis")]
-impl Drop for HeapTracker {
+impl Drop for RunPartTelemetry {
     fn drop(&mut self) {
-        let end_stats = dhat::HeapStats::get();
-        Event::add_to_local_parent("memory", || {
-            [(
-                "memory".into(),
-                format!(
-                    "{}",
-                    Size::from_bytes(end_stats.total_bytes - self.start_bytes)
-                )
-                .into(),
-            )]
+        let counters = COUNTERS
+            .lock()
+            .expect("Counters are not poisoned")
+            .iter()
+            .map(|counter| (counter.name, counter.total.swap(0, Ordering::Relaxed)))
+            .filter(|(_, total)| *total > 0)
+            .into_grouping_map()
+            .sum()
+            .into_iter()
+            .sorted()
+            .collect::<Vec<_>>();
+        if !counters.is_empty() {
+            Event::add_to_parent("counters", &self._root, || {
+                counters
+                    .into_iter()
+                    .map(|(name, total)| (name.into(), total.to_string().into()))
+            });
+        }
+    }
+}
+
+// Every count! call site that has run, so their totals can be collected for each part
+static COUNTERS: Mutex<Vec<&'static Counter>> = Mutex::new(Vec::new());
+
+// Each count! call site has its own atomic total, since solvers count from hot loops on several
+// threads. Only the first count takes the lock, to register the counter
+pub struct Counter {
+    name: &'static str,
+    total: AtomicU64,
+    registered: Once,
+}
+
+#[cfg_attr(not(feature = "all-days"), allow(dead_code))]
+impl Counter {
+    pub const fn new(name: &'static str) -> Self {
+        Counter {
+            name,
+            total: AtomicU64::new(0),
+            registered: Once::new(),
+        }
+    }
+
+    pub fn add(&'static self, amount: u64) {
+        self.registered.call_once(|| {
+            COUNTERS
+                .lock()
+                .expect("Counters are not poisoned")
+                .push(self)
         });
+        self.total.fetch_add(amount, Ordering::Relaxed);
     }
 }
 
+#[derive(Default)]
 struct DayResult {
     day: Cow<'static, str>,
     run_value: Cow<'static, str>,
+    answer: Option<Cow<'static, str>>,
     parse_time: Duration,
     run_time: Duration,
     total_time: Duration,
+    budget: Option<Duration>,
+    phases: Vec<PhaseResult>,
+    counters: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    #[cfg(feature = "memory-analysis")]
+    memory: Option<MemoryFigures>,
+    #[cfg(feature = "memory-analysis")]
+    parse_memory: Option<MemoryFigures>,
     #[cfg(feature = "memory-analysis")]
-    memory: Cow<'static, str>,
+    run_memory: Option<MemoryFigures>,
 }
 
-struct DayCollector {
-    day_results: Vec<DayResult>,
+struct PhaseResult {
+    name: Cow<'static, str>,
+    duration: Duration,
+    #[cfg(feature = "memory-analysis")]
+    memory: MemoryFigures,
 }
 
-impl Drop for DayCollector {
-    fn drop(&mut self) {
-        self.print_results()
-    }
+struct DayCollector {
+    day_results: Vec<DayResult>,
 }
 
 impl DayCollector {
@@ -151,75 +268,205 @@ impl DayCollector {
         self.day_results.extend(to_add)
     }
 
-    fn print_results(&self) {
-        self.day_results
+    // Takes the results so far, so each part only shows up in one report
+    fn report(&mut self, color: bool) -> String {
+        let rows = std::mem::take(&mut self.day_results)
+            .into_iter()
+            .into_group_map_by(|result| (result.day.clone(), result.run_value.clone()))
+            .into_iter()
+            .sorted_by(|(key1, _), (key2, _)| key1.cmp(key2))
+            .map(|(_, runs)| merge_runs(runs))
+            .collect::<Vec<_>>();
+        if rows.is_empty() {
+            return String::new();
+        }
+
+        let answers = rows
             .iter()
-            .sorted_by(|results1, results2| match results1.day.cmp(&results2.day) {
-                std::cmp::Ordering::Equal => results1.run_value.cmp(&results2.run_value),
-                result => result,
+            .map(|row| {
+                row.answer
+                    .as_deref()
+                    .map(|answer| answer.replace('\n', " "))
+                    .unwrap_or_else(|| "-".to_string())
             })
-            .for_each(|result| {
+            .collect::<Vec<_>>();
+        let day_width = rows.iter().map(|row| row.day.len()).fold(3, usize::max);
+        let part_width = rows
+            .iter()
+            .map(|row| row.run_value.len())
+            .fold(4, usize::max);
+        let answer_width = answers
+            .iter()
+            .map(|answer| answer.len())
+            .fold(6, usize::max);
+
+        let header = format!(
+            "{:<day_width$}  {:<part_width$}  {:>answer_width$}  {:>14}  {:>14}  {:>14}  {:>7}",
+            "day", "part", "answer", "parse", "run", "total", "budget"
+        );
+        let lines = rows.iter().zip(answers).flat_map(|(row, answer)| {
+            let budget = row.budget.unwrap_or(DEFAULT_BUDGET);
+            let main_line = format!(
+                "{:<day_width$}  {:<part_width$}  {:>answer_width$}  {}  {}  {}  {:>7}",
+                row.day,
+                row.run_value,
+                answer,
+                formatted_duration(&row.parse_time, budget / 20, color),
+                formatted_duration(&row.run_time, budget * 19 / 20, color),
+                formatted_duration(&row.total_time, budget, color),
+                row.budget
+                    .map(|budget| format!(
+                        "{:.0}%",
+                        row.total_time.as_secs_f64() / budget.as_secs_f64() * 100.0
+                    ))
+                    .unwrap_or_default(),
+            );
+            // Only figures that were recorded get a line
+            #[cfg(feature = "memory-analysis")]
+            let memory_lines = [
+                ("memory", row.memory),
+                ("parse memory", row.parse_memory),
+                ("run memory", row.run_memory),
+            ]
+            .into_iter()
+            .filter_map(|(name, memory)| {
+                memory.map(|memory| format!("    {:<30} {}", name, memory))
+            });
+            #[cfg(not(feature = "memory-analysis"))]
+            let memory_lines = std::iter::empty();
+            let phase_lines = row.phases.iter().map(move |phase| {
                 #[cfg(not(feature = "memory-analysis"))]
-                println!(
-                    "{} {:>11}, parse: {}, run: {}, total: {}",
-                    result.day,
-                    result.run_value,
-                    formatted_duration(&result.parse_time, 1),
-                    formatted_duration(&result.run_time, 19),
-                    formatted_duration(&result.total_time, 20),
-                );
+                {
+                    format!(
+                        "    {:<30} {}",
+                        phase.name,
+                        formatted_duration(&phase.duration, budget / 20, color)
+                    )
+                }
                 #[cfg(feature = "memory-analysis")]
-                println!(
-                    "{} {:>11}, parse: {}, run: {}, total: {}, memory: {:>8}",
-                    result.day,
-                    result.run_value,
-                    formatted_duration(&result.parse_time, 1),
-                    formatted_duration(&result.run_time, 19),
-                    formatted_duration(&result.total_time, 20),
-                    result.memory,
-                );
+                {
+                    format!(
+                        "    {:<30} {}, {}",
+                        phase.name,
+                        formatted_duration(&phase.duration, budget / 20, color),
+                        phase.memory
+                    )
+                }
             });
-        if self.day_results.len() > 1 {
-            let (total_parse, total_run, total) = self.day_results.iter().fold(
-                (Duration::ZERO, Duration::ZERO, Duration::ZERO),
-                |(total_parse, total_run, total), result| {
+            let counter_lines = row
+                .counters
+                .iter()
+                .map(|(name, total)| format!("    {:<30} {:>14}", name, total));
+
+            [main_line]
+                .into_iter()
+                .chain(memory_lines)
+                .chain(phase_lines)
+                .chain(counter_lines)
+                .collect::<Vec<_>>()
+        });
+        let totals = (rows.len() > 1).then(|| {
+            let (total_parse, total_run, total, total_budget) = rows.iter().fold(
+                (
+                    Duration::ZERO,
+                    Duration::ZERO,
+                    Duration::ZERO,
+                    Duration::ZERO,
+                ),
+                |(total_parse, total_run, total, total_budget), row| {
                     (
-                        total_parse + result.parse_time,
-                        total_run + result.run_time,
-                        total + result.total_time,
+                        total_parse + row.parse_time,
+                        total_run + row.run_time,
+                        total + row.total_time,
+                        total_budget + row.budget.unwrap_or(DEFAULT_BUDGET),
                     )
                 },
             );
-            println!(
-                "Totals,            parse: {}, run: {}, total: {}",
-                formatted_duration(&total_parse, 50),
-                formatted_duration(&total_run, 950),
-                formatted_duration(&total, 1000)
+            format!(
+                "{:<label_width$}  {}  {}  {}",
+                "Totals",
+                formatted_duration(&total_parse, total_budget / 20, color),
+                formatted_duration(&total_run, total_budget * 19 / 20, color),
+                formatted_duration(&total, total_budget, color),
+                label_width = day_width + part_width + answer_width + 4,
             )
-        }
+        });
+
+        [header]
+            .into_iter()
+            .chain(lines)
+            .chain(totals)
+            .map(|line| line + "\n")
+            .collect()
+    }
+}
+
+// Parts repeated with --iterations share a row with their mean times, while answers, counters
+// and memory figures come from the first run
+fn merge_runs(runs: Vec<DayResult>) -> DayResult {
+    let count = runs.len() as u32;
+    let mut runs = runs.into_iter();
+    let first = runs.next().expect("Groups are never empty");
+    let merged = runs.fold(first, |mut merged, run| {
+        merged.parse_time += run.parse_time;
+        merged.run_time += run.run_time;
+        merged.total_time += run.total_time;
+        run.phases.iter().for_each(|phase| {
+            if let Some(merged_phase) = merged
+                .phases
+                .iter_mut()
+                .find(|merged_phase| merged_phase.name == phase.name)
+            {
+                merged_phase.duration += phase.duration;
+            }
+        });
+        merged
+    });
+
+    DayResult {
+        parse_time: merged.parse_time / count,
+        run_time: merged.run_time / count,
+        total_time: merged.total_time / count,
+        phases: merged
+            .phases
+            .into_iter()
+            .map(|phase| PhaseResult {
+                duration: phase.duration / count,
+                ..phase
+            })
+            .collect(),
+        ..merged
     }
 }
 
 struct DayReporter {
-    collector: DayCollector,
+    collector: Arc<Mutex<DayCollector>>,
+    trace: Option<TraceExport>,
 }
 
 impl Reporter for DayReporter {
-    fn report(&mut self, spans: &[minitrace::prelude::SpanRecord]) {
+    fn report(&mut self, spans: &[SpanRecord]) {
+        if let Some(trace) = &mut self.trace {
+            trace.add_spans(spans);
+        }
+
         let results = spans
             .iter()
             .map(|span| (span.trace_id, span))
             .into_group_map()
             .into_values()
             .filter_map(|record| {
+                // Streamed inputs alternate between parsing and running so phases are summed
                 let parse_time = record
                     .iter()
-                    .find(|span| span.name == "parse_input")
-                    .map(|span| Duration::from_nanos(span.duration_ns));
+                    .filter(|span| span.name == "parse_input")
+                    .map(|span| Duration::from_nanos(span.duration_ns))
+                    .reduce(|total, duration| total + duration);
                 let run_time = record
                     .iter()
-                    .find(|span| span.name == "run_time")
-                    .map(|span| Duration::from_nanos(span.duration_ns));
+                    .filter(|span| span.name == "run_time")
+                    .map(|span| Duration::from_nanos(span.duration_ns))
+                    .reduce(|total, duration| total + duration);
 
                 match (parse_time, run_time) {
                     (Some(parse_time), Some(run_time)) => Some((record, parse_time, run_time)),
@@ -227,73 +474,112 @@ impl Reporter for DayReporter {
                 }
             })
             .map(|(record, parse_time, run_time)| {
-                #[cfg(feature = "memory-analysis")]
-                {
-                    let (day, run_value, total_time, memory) = record
-                        .into_iter()
-                        .find(|span| span.name == "run_part_total")
-                        .map(|span| {
-                            (
-                                &span.properties[0].1,
-                                &span.properties[1].1,
-                                Duration::from_nanos(span.duration_ns),
-                                span.events
-                                    .iter()
-                                    .find(|record| record.name == "memory")
-                                    .and_then(|record| {
-                                        record
-                                            .properties
-                                            .iter()
-                                            .find(|(name, _)| name == "memory")
-                                            .map(|(_, size)| size.clone())
-                                    })
-                                    .expect("memory recorded"),
-                            )
-                        })
-                        .expect("Total exists");
-                    DayResult {
-                        day: day.clone(),
-                        run_value: run_value.clone(),
-                        parse_time,
-                        run_time,
-                        total_time,
-                        memory,
-                    }
-                }
-                #[cfg(not(feature = "memory-analysis"))]
-                {
-                    let (day, run_value, total_time) = record
-                        .into_iter()
-                        .find(|span| span.name == "run_part_total")
-                        .map(|span| {
-                            (
-                                &span.properties[0].1,
-                                &span.properties[1].1,
-                                Duration::from_nanos(span.duration_ns),
-                            )
-                        })
-                        .expect("Total exists");
-                    DayResult {
-                        day: day.clone(),
-                        run_value: run_value.clone(),
-                        parse_time,
-                        run_time,
-                        total_time,
-                    }
+                // Spans from trace_phase! are combined by name, in the order they first started
+                let phases = record
+                    .iter()
+                    .filter(|span| {
+                        !matches!(
+                            span.name.as_ref(),
+                            "parse_input" | "run_time" | "run_part_total"
+                        )
+                    })
+                    .sorted_by_key(|span| span.begin_time_unix_ns)
+                    .fold(Vec::new(), |mut phases: Vec<PhaseResult>, span| {
+                        let duration = Duration::from_nanos(span.duration_ns);
+                        match phases.iter_mut().find(|phase| phase.name == span.name) {
+                            Some(phase) => {
+                                phase.duration += duration;
+                                #[cfg(feature = "memory-analysis")]
+                                {
+                                    phase.memory =
+                                        phase.memory.merge(memory([*span]).unwrap_or_default());
+                                }
+                            }
+                            None => phases.push(PhaseResult {
+                                name: span.name.clone(),
+                                duration,
+                                #[cfg(feature = "memory-analysis")]
+                                memory: memory([*span]).unwrap_or_default(),
+                            }),
+                        }
+                        phases
+                    });
+                let counters = record
+                    .iter()
+                    .flat_map(|span| span.events.iter())
+                    .filter(|event| event.name == "counters")
+                    .flat_map(|event| event.properties.iter().cloned())
+                    .collect::<Vec<_>>();
+                let total = record
+                    .iter()
+                    .find(|span| span.name == "run_part_total")
+                    .expect("Total exists");
+
+                DayResult {
+                    day: total.properties[0].1.clone(),
+                    run_value: total.properties[1].1.clone(),
+                    answer: total
+                        .events
+                        .iter()
+                        .find(|event| event.name == "answer")
+                        .and_then(|event| event.properties.first())
+                        .map(|(_, answer)| answer.clone()),
+                    parse_time,
+                    run_time,
+                    total_time: Duration::from_nanos(total.duration_ns),
+                    budget: total
+                        .properties
+                        .iter()
+                        .find(|(name, _)| name == "budget_ns")
+                        .and_then(|(_, budget)| budget.parse().ok())
+                        .map(Duration::from_nanos),
+                    phases,
+                    counters,
+                    #[cfg(feature = "memory-analysis")]
+                    memory: memory([*total]),
+                    #[cfg(feature = "memory-analysis")]
+                    parse_memory: memory(
+                        record
+                            .iter()
+                            .copied()
+                            .filter(|span| span.name == "parse_input"),
+                    ),
+                    #[cfg(feature = "memory-analysis")]
+                    run_memory: memory(
+                        record
+                            .iter()
+                            .copied()
+                            .filter(|span| span.name == "run_time"),
+                    ),
                 }
             });
-        self.collector.add_results(results);
+        self.collector
+            .lock()
+            .expect("Collector is not poisoned")
+            .add_results(results);
     }
 }
 
+#[cfg(feature = "memory-analysis")]
+fn memory<'a>(spans: impl IntoIterator<Item = &'a SpanRecord>) -> Option<MemoryFigures> {
+    spans
+        .into_iter()
+        .flat_map(|span| span.events.iter())
+        .filter(|event| event.name == "memory")
+        .filter_map(MemoryFigures::from_event)
+        .reduce(MemoryFigures::merge)
+}
+
 struct NopReporter;
 
 impl Reporter for NopReporter {
     fn report(&mut self, _spans: &[minitrace::prelude::SpanRecord]) {}
 }
 
-fn formatted_duration(duration: &Duration, baseline_ms: u64) -> String {
-    let baseline = Duration::from_millis(baseline_ms);
+fn formatted_duration(duration: &Duration, baseline: Duration, color: bool) -> String {
+    if !color {
+        return format!("{:>14?}", duration);
+    }
 
     let time_color = match duration {
         x if x <= &(baseline / 4) => "\x1b[92m",
@@ -306,3 +592,45 @@ fn formatted_duration(duration: &Duration, baseline_ms: u64) -> String {
 
     format!("{}{:>14?}\x1b[0m", time_color, duration)
 }
+
+#[test]
+fn reports_one_aligned_row_per_part() {
+    let mut collector = DayCollector::new();
+    collector.add_results(
+        [
+            ("day02", "part1", "12", 4),
+            ("day01", "part2", "31", 2),
+            ("day01", "part2", "31", 4),
+            ("day01", "part1", "1100", 6),
+        ]
+        .into_iter()
+        .map(|(day, run_value, answer, millis)| DayResult {
+            day: day.into(),
+            run_value: run_value.into(),
+            answer: Some(answer.into()),
+            parse_time: Duration::from_millis(1),
+            run_time: Duration::from_millis(millis - 1),
+            total_time: Duration::from_millis(millis),
+            budget: Some(Duration::from_millis(20)),
+            ..Default::default()
+        }),
+    );
+
+    let report = collector.report(false);
+    let lines = report.lines().collect::<Vec<_>>();
+
+    assert!(!report.contains('\x1b'));
+    assert_eq!(lines.len(), 5);
+    assert!(lines[0].starts_with("day    part   answer"));
+    assert!(lines[1].starts_with("day01  part1    1100"));
+    assert!(lines[2].starts_with("day01  part2      31"));
+    assert!(lines[2].contains("3ms"));
+    assert!(lines[2].ends_with("15%"));
+    assert!(lines[3].starts_with("day02  part1      12"));
+    assert!(lines[4].starts_with("Totals"));
+    assert!(lines[4].contains("13ms"));
+    assert!(lines[1..4]
+        .iter()
+        .all(|line| line.find("ms") == lines[1].find("ms")));
+    assert!(collector.report(false).is_empty());
+}
@@ -0,0 +1,257 @@
+use std::{borrow::Cow, fs::write, path::PathBuf};
+
+use clap::{value_parser, Arg, ValueEnum};
+use itertools::Itertools;
+use minitrace::collector::SpanRecord;
+use serde_json::{json, Map, Value};
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum TraceFormat {
+    // Trace event json, which can be opened in Perfetto or chrome://tracing
+    Chrome,
+    // The OpenTelemetry protocol's json encoding, for collectors and other tracing tools
+    Otlp,
+}
+
+pub fn trace_args() -> [Arg; 2] {
+    [
+        Arg::new("trace-out")
+            .long("trace-out")
+            .global(true)
+            .value_parser(value_parser!(PathBuf))
+            .help("Write every span recorded while running to this file"),
+        Arg::new("trace-format")
+            .long("trace-format")
+            .global(true)
+            .value_parser(value_parser!(TraceFormat))
+            .default_value("chrome")
+            .help("The format of the --trace-out file"),
+    ]
+}
+
+// Keeps every span reported and writes them all out once reporting is finished
+pub struct TraceExport {
+    file: PathBuf,
+    format: TraceFormat,
+    spans: Vec<SpanRecord>,
+}
+
+impl TraceExport {
+    pub fn new(file: PathBuf, format: TraceFormat) -> Self {
+        TraceExport {
+            file,
+            format,
+            spans: Vec::new(),
+        }
+    }
+
+    pub fn add_spans(&mut self, spans: &[SpanRecord]) {
+        self.spans.extend_from_slice(spans)
+    }
+}
+
+impl Drop for TraceExport {
+    fn drop(&mut self) {
+        let trace = match self.format {
+            TraceFormat::Chrome => chrome_trace(&self.spans),
+            TraceFormat::Otlp => otlp_trace(&self.spans),
+        };
+
+        match write(&self.file, trace.to_string()) {
+            Ok(_) => println!("Wrote trace to {}", self.file.display()),
+            Err(e) => eprintln!("Could not write trace to {}: {}", self.file.display(), e),
+        }
+    }
+}
+
+type Properties = [(Cow<'static, str>, Cow<'static, str>)];
+
+fn json_property((key, value): &(Cow<'static, str>, Cow<'static, str>)) -> (String, Value) {
+    (key.to_string(), Value::from(value.as_ref()))
+}
+
+fn property<'a>(properties: &'a Properties, name: &str) -> Option<&'a str> {
+    properties
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_ref())
+}
+
+// Each run of a day part gets its own thread so Perfetto shows them as separate tracks
+fn chrome_trace(spans: &[SpanRecord]) -> Value {
+    let traces = spans
+        .iter()
+        .map(|span| span.trace_id)
+        .unique()
+        .collect::<Vec<_>>();
+    let thread = |span: &SpanRecord| {
+        traces
+            .iter()
+            .position(|trace_id| *trace_id == span.trace_id)
+            .unwrap_or_default()
+    };
+    let microseconds = |nanoseconds: u64| nanoseconds as f64 / 1000.0;
+
+    let thread_names = spans
+        .iter()
+        .filter(|span| span.parent_id.0 == 0)
+        .map(|span| {
+            json!({
+                "name": "thread_name",
+                "ph": "M",
+                "pid": 1,
+                "tid": thread(span),
+                "args": {
+                    "name": format!(
+                        "{} {}",
+                        property(&span.properties, "day").unwrap_or_default(),
+                        property(&span.properties, "run_value").unwrap_or_default()
+                    )
+                },
+            })
+        });
+    let complete_events = spans.iter().flat_map(|span| {
+        [json!({
+            "name": span.name,
+            "ph": "X",
+            "pid": 1,
+            "tid": thread(span),
+            "ts": microseconds(span.begin_time_unix_ns),
+            "dur": microseconds(span.duration_ns),
+            "args": span.properties.iter().map(json_property).collect::<Map<_, _>>(),
+        })]
+        .into_iter()
+        .chain(span.events.iter().map(move |event| {
+            json!({
+                "name": event.name,
+                "ph": "i",
+                "s": "t",
+                "pid": 1,
+                "tid": thread(span),
+                "ts": microseconds(event.timestamp_unix_ns),
+                "args": event.properties.iter().map(json_property).collect::<Map<_, _>>(),
+            })
+        }))
+    });
+
+    json!({
+        "traceEvents": thread_names.chain(complete_events).collect::<Vec<_>>(),
+        "displayTimeUnit": "ns",
+    })
+}
+
+fn otlp_attributes(properties: &Properties) -> Value {
+    properties
+        .iter()
+        .map(|(key, value)| json!({ "key": key, "value": { "stringValue": value } }))
+        .collect()
+}
+
+fn otlp_trace(spans: &[SpanRecord]) -> Value {
+    let spans = spans
+        .iter()
+        .map(|span| {
+            json!({
+                "traceId": format!("{:032x}", span.trace_id.0),
+                "spanId": format!("{:016x}", span.span_id.0),
+                "parentSpanId": if span.parent_id.0 == 0 {
+                    String::new()
+                } else {
+                    format!("{:016x}", span.parent_id.0)
+                },
+                "name": span.name,
+                "kind": 1,
+                "startTimeUnixNano": span.begin_time_unix_ns.to_string(),
+                "endTimeUnixNano": (span.begin_time_unix_ns + span.duration_ns).to_string(),
+                "attributes": otlp_attributes(&span.properties),
+                "events": span.events.iter().map(|event| {
+                    json!({
+                        "timeUnixNano": event.timestamp_unix_ns.to_string(),
+                        "name": event.name,
+                        "attributes": otlp_attributes(&event.properties),
+                    })
+                }).collect::<Vec<_>>(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "resourceSpans": [{
+            "resource": {
+                "attributes": [
+                    { "key": "service.name", "value": { "stringValue": "adventofcode2024" } }
+                ]
+            },
+            "scopeSpans": [{
+                "scope": { "name": "adventofcode2024", "version": env!("CARGO_PKG_VERSION") },
+                "spans": spans,
+            }],
+        }]
+    })
+}
+
+#[cfg(test)]
+fn sample_spans() -> Vec<SpanRecord> {
+    use minitrace::collector::{EventRecord, SpanId, TraceId};
+
+    vec![
+        SpanRecord {
+            trace_id: TraceId(1),
+            span_id: SpanId(1),
+            parent_id: SpanId(0),
+            begin_time_unix_ns: 1_000_000,
+            duration_ns: 5_000,
+            name: "run_part_total".into(),
+            properties: vec![
+                ("day".into(), "day01".into()),
+                ("run_value".into(), "part1".into()),
+            ],
+            events: vec![],
+        },
+        SpanRecord {
+            trace_id: TraceId(1),
+            span_id: SpanId(2),
+            parent_id: SpanId(1),
+            begin_time_unix_ns: 1_001_000,
+            duration_ns: 2_000,
+            name: "run_time".into(),
+            properties: vec![],
+            events: vec![EventRecord {
+                name: "memory".into(),
+                timestamp_unix_ns: 1_002_000,
+                properties: vec![("memory".into(), "1 KiB".into())],
+            }],
+        },
+    ]
+}
+
+#[test]
+fn exports_chrome_traces() {
+    let trace = chrome_trace(&sample_spans());
+    let events = trace["traceEvents"].as_array().expect("Has events");
+
+    assert_eq!(events[0]["ph"], "M");
+    assert_eq!(events[0]["args"]["name"], "day01 part1");
+    assert_eq!(events[1]["name"], "run_part_total");
+    assert_eq!(events[1]["ts"], 1000.0);
+    assert_eq!(events[1]["dur"], 5.0);
+    assert_eq!(events[1]["args"]["day"], "day01");
+    assert_eq!(events[2]["name"], "run_time");
+    assert_eq!(events[3]["ph"], "i");
+    assert_eq!(events[3]["args"]["memory"], "1 KiB");
+}
+
+#[test]
+fn exports_otlp_traces() {
+    let trace = otlp_trace(&sample_spans());
+    let spans = trace["resourceSpans"][0]["scopeSpans"][0]["spans"]
+        .as_array()
+        .expect("Has spans");
+
+    assert_eq!(spans[0]["traceId"], format!("{:032x}", 1));
+    assert_eq!(spans[0]["parentSpanId"], "");
+    assert_eq!(spans[0]["endTimeUnixNano"], "1005000");
+    assert_eq!(spans[0]["attributes"][1]["value"]["stringValue"], "part1");
+    assert_eq!(spans[1]["parentSpanId"], format!("{:016x}", 1));
+    assert_eq!(spans[1]["events"][0]["name"], "memory");
+}
@@ -1,59 +1,40 @@
 #![feature(iter_map_windows)]
+// Only some of the days use the feature
+#![cfg_attr(not(feature = "all-days"), allow(unused_features))]
+mod completions;
 mod days;
 mod fetch_input;
 mod libs;
+mod run;
+mod scaffold;
+mod watch;
 
 use crate::libs::{
-    cli::{Command, PART_NAMES},
+    cli::{
+        flag_arg, iterations, iterations_arg, registered_days, set_iterations, Command, PART_NAMES,
+    },
+    config::{check_config, load_config},
     problem::ProblemResult,
+    profile::{profile_arg, set_profile},
 };
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use clap::Command as ClapCommand;
-use days::{
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25,
-};
-use libs::cli::AsCommand;
+use std::time::Instant;
 
+#[cfg(feature = "profile")]
+use libs::cpu_profile::{profile_args, set_profile_output};
 #[cfg(feature = "telemetry")]
-use libs::telemetry::Telemetry;
+use libs::{telemetry::Telemetry, trace_export::trace_args};
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 fn main() -> Result<()> {
-    #[cfg(feature = "telemetry")]
-    let _telemetry = Telemetry::init_telemetry();
-
-    let commands: Vec<(&str, &dyn Command)> = vec![
-        day01::DAY_01.as_command(),
-        day02::DAY_02.as_command(),
-        day03::DAY_03.as_command(),
-        day04::DAY_04.as_command(),
-        day05::DAY_05.as_command(),
-        day06::DAY_06.as_command(),
-        day07::DAY_07.as_command(),
-        day08::DAY_08.as_command(),
-        day09::DAY_09.as_command(),
-        day10::DAY_10.as_command(),
-        day11::DAY_11.as_command(),
-        day12::DAY_12.as_command(),
-        day13::DAY_13.as_command(),
-        day14::DAY_14.as_command(),
-        day15::DAY_15.as_command(),
-        day16::DAY_16.as_command(),
-        day17::DAY_17.as_command(),
-        day18::DAY_18.as_command(),
-        day19::DAY_19.as_command(),
-        day20::DAY_20.as_command(),
-        day21::DAY_21.as_command(),
-        day22::DAY_22.as_command(),
-        day23::DAY_23.as_command(),
-        day24::DAY_24.as_command(),
-        day25::DAY_25.as_command(),
-    ]
-    .into_iter()
-    .map(|command| (command.get_name(), command))
-    .collect();
+    load_config()?;
+
+    let commands: Vec<(&str, &dyn Command)> = registered_days()
+        .into_iter()
+        .map(|command| (command.get_name(), command))
+        .collect();
 
     let subcommands = commands
         .iter()
@@ -62,6 +43,22 @@ fn main() -> Result<()> {
 
     let download_command = fetch_input::command();
     let download_command_name = download_command.get_name().to_string();
+    let submit_command = fetch_input::submit_command();
+    let submit_command_name = submit_command.get_name().to_string();
+    let show_command = fetch_input::show_command();
+    let show_command_name = show_command.get_name().to_string();
+    let login_command = fetch_input::login_command();
+    let login_command_name = login_command.get_name().to_string();
+    let new_day_command = scaffold::command();
+    let new_day_command_name = new_day_command.get_name().to_string();
+    let run_command = run::command();
+    let run_command_name = run_command.get_name().to_string();
+    let watch_command = watch::command();
+    let watch_command_name = watch_command.get_name().to_string();
+    let completions_command = completions::command();
+    let completions_command_name = completions_command.get_name().to_string();
+    let manpage_command = completions::manpage_command();
+    let manpage_command_name = manpage_command.get_name().to_string();
 
     let all_days = commands.iter().flat_map(|(name, command)| {
         command
@@ -71,38 +68,148 @@ fn main() -> Result<()> {
             .collect::<Vec<_>>()
     });
 
-    let all_days_command =
-        ClapCommand::new("all_days").about("Runs all days in a row and gets the total time.");
+    let all_days_command = ClapCommand::new("all_days")
+        .about("Runs all days in a row and gets the total time.")
+        .arg(flag_arg(
+            "enforce-budgets",
+            'e',
+            "Fail if any part takes longer than its time budget, which a day's budgets table in aoc.toml can override",
+        ));
 
-    let matches = ClapCommand::new("Advent of Code 2024")
+    let app = ClapCommand::new("Advent of Code 2024")
         .version(VERSION)
         .about("Run the advent of code problems from this main program")
         .arg_required_else_help(true)
         .subcommand_required(true)
+        .arg(profile_arg())
+        .arg(iterations_arg())
         .subcommand(download_command)
+        .subcommand(submit_command)
+        .subcommand(show_command)
+        .subcommand(login_command)
+        .subcommand(new_day_command)
+        .subcommand(run_command)
+        .subcommand(watch_command)
+        .subcommand(completions_command)
+        .subcommand(manpage_command)
         .subcommand(all_days_command)
-        .subcommands(subcommands)
-        .get_matches();
+        .subcommands(subcommands);
+    #[cfg(feature = "telemetry")]
+    let app = app.args(trace_args());
+    #[cfg(feature = "profile")]
+    let app = app.args(profile_args());
+    check_config(&app)?;
+
+    // Completions and man pages are generated from the whole command tree
+    let matches = app.clone().get_matches();
 
-    matches
+    #[cfg(feature = "telemetry")]
+    let telemetry = Telemetry::init_telemetry(&matches);
+
+    set_profile(&matches);
+    set_iterations(&matches);
+    #[cfg(feature = "profile")]
+    set_profile_output(&matches);
+
+    let result = matches
         .subcommand_matches(&download_command_name)
         .map(fetch_input::run)
         .or_else(|| {
-            matches.subcommand_matches("all_days").map(|_| {
+            matches
+                .subcommand_matches(&show_command_name)
+                .map(fetch_input::show)
+        })
+        .or_else(|| {
+            matches
+                .subcommand_matches(&login_command_name)
+                .map(fetch_input::login)
+        })
+        .or_else(|| {
+            matches
+                .subcommand_matches(&new_day_command_name)
+                .map(scaffold::run)
+        })
+        .or_else(|| {
+            matches.subcommand_matches(&run_command_name).map(|args| {
+                run::run(args, &commands).map(|result| {
+                    println!("{}", result);
+                })
+            })
+        })
+        .or_else(|| {
+            matches.subcommand_matches(&watch_command_name).map(|args| {
+                watch::run(args, &commands, || {
+                    #[cfg(feature = "telemetry")]
+                    telemetry.report();
+                })
+            })
+        })
+        .or_else(|| {
+            matches
+                .subcommand_matches(&completions_command_name)
+                .map(|args| completions::run(args, app.clone()))
+        })
+        .or_else(|| {
+            matches
+                .subcommand_matches(&manpage_command_name)
+                .map(|args| completions::manpage(args, app.clone()))
+        })
+        .or_else(|| {
+            matches
+                .subcommand_matches(&submit_command_name)
+                .map(|args| {
+                    fetch_input::submit(args, |day, part| {
+                        commands
+                            .iter()
+                            .find(|(name, _)| *name == format!("day{:0>2}", day))
+                            .filter(|(_, command)| command.get_parts().contains(&part))
+                            .ok_or_else(|| anyhow!("No solver for day {} part {}", day, part + 1))
+                            .and_then(|(_, command)| command.run_part(part))
+                    })
+                })
+        })
+        .or_else(|| {
+            matches.subcommand_matches("all_days").map(|args| {
                 all_days
                     .map(|(day, command, part)| {
                         println!(
                             "=============Running {:}, {:}=============",
                             day, PART_NAMES[part]
                         );
+                        // Each part runs --iterations times, so its budget is held against one run
+                        let start = Instant::now();
                         let result = command.run_part(part);
-                        result.map(|r| (r, day, part))
+                        let elapsed = start.elapsed().div_f64(iterations() as f64);
+                        result.map(|r| (r, day, part, elapsed, command.get_budget(part)))
                     })
                     .collect::<Result<Vec<_>>>()
-                    .map(|results| {
-                        results.into_iter().for_each(|(result, day, part)| {
-                            println!("{} {} Result: {}", day, PART_NAMES[part], result);
-                        })
+                    .and_then(|results| {
+                        // With telemetry the answers are part of the report instead
+                        #[cfg(not(feature = "telemetry"))]
+                        results.iter().for_each(|(result, day, part, _, _)| {
+                            println!("{} {} Result: {}", day, PART_NAMES[*part], result);
+                        });
+
+                        let over_budget = results
+                            .iter()
+                            .filter(|(_, _, _, elapsed, budget)| elapsed > budget)
+                            .map(|(_, day, part, elapsed, budget)| {
+                                format!(
+                                    "{} {} took {:?}, over its budget of {:?}",
+                                    day, PART_NAMES[*part], elapsed, budget
+                                )
+                            })
+                            .collect::<Vec<_>>();
+
+                        if args.get_flag("enforce-budgets") && !over_budget.is_empty() {
+                            Err(anyhow!(
+                                "{} parts went over budget:\n{}",
+                                over_budget.len(),
+                                over_budget.join("\n")
+                            ))
+                        } else {
+                            Ok(())
+                        }
                     })
             })
         })
@@ -121,5 +228,10 @@ fn main() -> Result<()> {
                         println!("{}", result);
                     })
                 })
-        })
+        });
+
+    #[cfg(feature = "telemetry")]
+    telemetry.report();
+
+    result
 }
@@ -0,0 +1,63 @@
+use anyhow::{anyhow, Result};
+use clap::{value_parser, ArgMatches, Args, Command as ClapCommand};
+
+use crate::libs::{
+    cli::{parse_day, parse_input_choice, CliArgs, Command, InputChoice, RunSelection},
+    problem::ProblemResult,
+};
+
+#[derive(Args)]
+struct CommandLineArguments {
+    #[arg(
+        value_parser = parse_day,
+        help = "The day to run, either as a number or as dayNN"
+    )]
+    day: usize,
+
+    #[arg(
+        short,
+        long,
+        value_parser = value_parser!(u8).range(1..=2),
+        help = "Start from this part's arguments, without it every argument the day needs must be given"
+    )]
+    part: Option<u8>,
+
+    #[arg(
+        short,
+        long,
+        default_value = "real",
+        value_parser = parse_input_choice,
+        help = "real for input.txt, sample for the part's samples, or a file given as a path or a name in input/dayNN"
+    )]
+    input: InputChoice,
+
+    #[arg(
+        long,
+        num_args = 1..,
+        allow_hyphen_values = true,
+        help = "The day's own arguments, overriding the part's, e.g. --args --x_size 11. Must come last"
+    )]
+    args: Vec<String>,
+}
+
+pub fn command() -> ClapCommand {
+    CommandLineArguments::augment_args(ClapCommand::new("run"))
+        .about("Runs a day on a chosen input, with a part's arguments and any overrides")
+        .arg_required_else_help(true)
+}
+
+pub fn run(args: &ArgMatches, commands: &[(&str, &dyn Command)]) -> Result<ProblemResult> {
+    let arguments = CommandLineArguments::parse_output(args);
+    let day = format!("day{:0>2}", arguments.day);
+    let (_, command) = commands
+        .iter()
+        .find(|(name, _)| *name == day)
+        .ok_or_else(|| anyhow!("No solver for {}", day))?;
+
+    println!("=============Running {:}=============", day);
+    command.run_selection(&RunSelection {
+        part: arguments.part.map(|part| part as usize - 1),
+        input: arguments.input,
+        overrides: arguments.args,
+    })
+}
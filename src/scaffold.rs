@@ -0,0 +1,206 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Result};
+use clap::{value_parser, ArgMatches, Args, Command};
+use tap::Tap;
+
+use crate::libs::{
+    cli::{parse_day, CliArgs},
+    file_system::{file_to_string, save_string_to_file},
+};
+
+#[derive(Args)]
+struct CommandLineArguments {
+    #[arg(
+        value_parser = parse_day,
+        help = "The day to create, either as a number or as dayNN"
+    )]
+    day: usize,
+
+    #[arg(
+        long,
+        default_value = ".",
+        value_parser = value_parser!(PathBuf),
+        help = "The crate root containing Cargo.toml and src"
+    )]
+    root: PathBuf,
+}
+
+pub fn command() -> Command {
+    CommandLineArguments::augment_args(Command::new("new_day"))
+        .about("Creates src/days/day{}.rs from a template, adding its module and cargo feature")
+        .arg_required_else_help(true)
+}
+
+pub fn run(args: &ArgMatches) -> Result<()> {
+    let arguments = CommandLineArguments::parse_output(args);
+    let day = format!("{:0>2}", arguments.day);
+
+    let day_file = arguments
+        .root
+        .clone()
+        .tap_mut(|path| path.push(format!("src/days/day{}.rs", day)));
+    if day_file.exists() {
+        return Err(anyhow!("{} already exists", day_file.display()));
+    }
+
+    let mod_file = arguments
+        .root
+        .clone()
+        .tap_mut(|path| path.push("src/days/mod.rs"));
+    let manifest_file = arguments
+        .root
+        .clone()
+        .tap_mut(|path| path.push("Cargo.toml"));
+
+    let days_module = register_module(&file_to_string(&mod_file)?, &day);
+    let manifest = register_feature(&file_to_string(&manifest_file)?, &day)?;
+
+    println!("Creating {}", day_file.display());
+    save_string_to_file(&DAY_TEMPLATE.replace("NN", &day), &day_file)?;
+    save_string_to_file(&days_module, &mod_file)?;
+    save_string_to_file(&manifest, &manifest_file).map_err(|e| e.into())
+}
+
+const DAY_TEMPLATE: &str = r#"use crate::libs::{
+    cli::{new_cli_problem, AsCommand, CliProblem, Freeze, RegisteredDay},
+    parse::{parse_lines, parse_usize, ParserExt, StringInput, StringParse},
+    problem::Problem,
+};
+use adventofcode_macro::{problem_day, problem_parse};
+use chumsky::{error::Rich, extra, Parser};
+use clap::Args;
+use std::sync::LazyLock;
+
+pub static DAY_NN: LazyLock<CliProblem<DayNN, CommandLineArguments, Freeze>> =
+    LazyLock::new(|| {
+        new_cli_problem(
+            "dayNN",
+            "TODO: what the day computes",
+            "TODO: what the input file contains",
+        )
+        .with_part(
+            "TODO: what part 1 computes",
+            CommandLineArguments {},
+            vec![],
+        )
+        .freeze()
+    });
+
+inventory::submit! {
+    RegisteredDay(|| DAY_NN.as_command())
+}
+
+#[derive(Args, Clone)]
+pub struct CommandLineArguments {}
+
+pub struct DayNN(Vec<usize>);
+
+#[problem_parse]
+fn parse<'a>() -> impl Parser<'a, &'a str, DayNN, extra::Err<Rich<'a, char>>> {
+    parse_lines(parse_usize()).map(DayNN).end()
+}
+
+#[problem_day]
+fn run(DayNN(input): DayNN, _arguments: &CommandLineArguments) -> usize {
+    input.into_iter().sum()
+}
+"#;
+
+fn register_module(days_module: &str, day: &str) -> String {
+    let module = format!("pub(crate) mod day{};", day);
+    let lines = days_module.lines().collect::<Vec<_>>();
+    if lines.contains(&module.as_str()) {
+        return days_module.to_string();
+    }
+
+    let module_day = |line: &str| {
+        line.strip_prefix("pub(crate) mod day")
+            .and_then(|line| line.strip_suffix(';'))
+            .map(str::to_string)
+    };
+    // Goes above the first later day's attribute, or below the last day to keep them sorted
+    let insert_at = lines
+        .iter()
+        .position(|line| module_day(line).is_some_and(|other| other.as_str() > day))
+        .map(|index| match index.checked_sub(1) {
+            Some(attribute) if lines[attribute].starts_with("#[cfg(") => attribute,
+            _ => index,
+        })
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|line| module_day(line).is_some())
+                .map(|index| index + 1)
+        })
+        .unwrap_or(lines.len());
+    let registration = format!("#[cfg(feature = \"day{}\")]\n{}", day, module);
+
+    lines[..insert_at]
+        .iter()
+        .copied()
+        .chain([registration.as_str()])
+        .chain(lines[insert_at..].iter().copied())
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+// Adds a feature for the day and enables it as part of all-days
+fn register_feature(manifest: &str, day: &str) -> Result<String> {
+    let feature = format!("day{} = []", day);
+    let all_days_entry = format!("    \"day{}\",", day);
+    let lines = manifest.lines().collect::<Vec<_>>();
+
+    let all_days_end = lines
+        .iter()
+        .position(|line| *line == "all-days = [")
+        .and_then(|start| {
+            lines[start..]
+                .iter()
+                .position(|line| *line == "]")
+                .map(|end| start + end)
+        })
+        .ok_or_else(|| anyhow!("Cargo.toml has no all-days feature"))?;
+    let features_end = lines
+        .iter()
+        .rposition(|line| line.starts_with("day") && line.ends_with(" = []"))
+        .ok_or_else(|| anyhow!("Cargo.toml has no day features"))?;
+
+    Ok(lines
+        .iter()
+        .enumerate()
+        .flat_map(|(index, line)| {
+            let before = (index == all_days_end && !lines.contains(&all_days_entry.as_str()))
+                .then_some(all_days_entry.as_str());
+            let after = (index == features_end && !lines.contains(&feature.as_str()))
+                .then_some(feature.as_str());
+            before.into_iter().chain([*line]).chain(after)
+        })
+        .map(|line| format!("{}\n", line))
+        .collect())
+}
+
+#[test]
+fn registers_new_days() {
+    let manifest = "[features]\nall-days = [\n    \"day01\",\n]\nday01 = []\n\n[dependencies]\n";
+
+    assert_eq!(
+        register_feature(manifest, "02").expect("Registers"),
+        "[features]\nall-days = [\n    \"day01\",\n    \"day02\",\n]\nday01 = []\nday02 = []\n\n[dependencies]\n"
+    );
+    assert_eq!(
+        register_module(
+            "#[cfg(feature = \"day01\")]\npub(crate) mod day01;\n#[cfg(feature = \"day03\")]\npub(crate) mod day03;\n",
+            "02"
+        ),
+        "#[cfg(feature = \"day01\")]\npub(crate) mod day01;\n#[cfg(feature = \"day02\")]\npub(crate) mod day02;\n#[cfg(feature = \"day03\")]\npub(crate) mod day03;\n"
+    );
+    // Anything else in the module is kept in place
+    assert_eq!(
+        register_module(
+            "// The days\n#[cfg(feature = \"day01\")]\npub(crate) mod day01;\n\npub(crate) use day01::DAY_01;\n",
+            "02"
+        ),
+        "// The days\n#[cfg(feature = \"day01\")]\npub(crate) mod day01;\n#[cfg(feature = \"day02\")]\npub(crate) mod day02;\n\npub(crate) use day01::DAY_01;\n"
+    );
+}
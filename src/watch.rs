@@ -0,0 +1,175 @@
+use std::{
+    io::ErrorKind,
+    path::Path,
+    sync::mpsc::{channel, Receiver, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Result};
+use clap::{value_parser, ArgMatches, Args, Command as ClapCommand};
+use console::{style, Key, Term};
+use notify::{recommended_watcher, EventKind, RecursiveMode, Watcher};
+
+use crate::libs::{
+    cli::{parse_day, CliArgs, Command, PART_NAMES},
+    profile::input_directory,
+};
+
+#[derive(Args)]
+struct CommandLineArguments {
+    #[arg(
+        value_parser = parse_day,
+        help = "The day to watch, either as a number or as dayNN"
+    )]
+    day: usize,
+
+    #[arg(
+        short,
+        long,
+        default_value = "1",
+        value_parser = value_parser!(u8).range(1..=2),
+        help = "The part to run first, switch parts with p while watching"
+    )]
+    part: u8,
+}
+
+enum WatchEvent {
+    InputChanged,
+    Key(Key),
+}
+
+pub fn command() -> ClapCommand {
+    CommandLineArguments::augment_args(ClapCommand::new("watch"))
+        .about("Reruns a day's samples and real input every time a file in its input directory changes")
+        .arg_required_else_help(true)
+}
+
+// Reports are printed after each rerun, so they don't pile up until watching stops
+pub fn run(args: &ArgMatches, commands: &[(&str, &dyn Command)], report: impl Fn()) -> Result<()> {
+    let arguments = CommandLineArguments::parse_output(args);
+    let day = format!("day{:0>2}", arguments.day);
+    let (_, command) = commands
+        .iter()
+        .find(|(name, _)| *name == day)
+        .ok_or_else(|| anyhow!("No solver for {}", day))?;
+    let parts = command.get_parts();
+    let mut part = parts
+        .iter()
+        .position(|part| *part == arguments.part as usize - 1)
+        .ok_or_else(|| anyhow!("{} has no part {}", day, arguments.part))?;
+
+    let (sender, events) = channel();
+
+    let key_sender = sender.clone();
+    let mut watcher = recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let changed_input = event.is_ok_and(|event| {
+            matches!(
+                event.kind,
+                EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+            ) && event
+                .paths
+                .iter()
+                .any(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        });
+        if changed_input {
+            let _ = sender.send(WatchEvent::InputChanged);
+        }
+    })?;
+    // Samples live in input/dayNN while the real input can be under a profile's directory
+    let sample_directory = Path::new("input").join(&day);
+    let input_directory = input_directory(&day);
+    watcher.watch(&sample_directory, RecursiveMode::NonRecursive)?;
+    if input_directory != sample_directory && input_directory.exists() {
+        watcher.watch(&input_directory, RecursiveMode::NonRecursive)?;
+    }
+
+    let term = Term::stdout();
+    if term.is_term() {
+        thread::spawn(move || {
+            loop {
+                let key = match Term::stdout().read_key() {
+                    Ok(key) => key,
+                    // Ctrl-C while reading a key can come back as an interrupted read
+                    Err(e) if e.kind() == ErrorKind::Interrupted => Key::CtrlC,
+                    Err(_) => break,
+                };
+                if key_sender.send(WatchEvent::Key(key)).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    loop {
+        run_part(&term, *command, parts[part])?;
+        report();
+
+        match next_event(&events) {
+            Some(WatchEvent::InputChanged) => {}
+            Some(WatchEvent::Key(Key::Char('p') | Key::Tab)) => part = (part + 1) % parts.len(),
+            Some(WatchEvent::Key(Key::Char('q') | Key::Escape | Key::CtrlC)) | None => {
+                return Ok(())
+            }
+            Some(WatchEvent::Key(_)) => {}
+        }
+    }
+}
+
+// Editors often write a file in several steps, so changes that arrive together cause one rerun
+fn next_event(events: &Receiver<WatchEvent>) -> Option<WatchEvent> {
+    let event = events.recv().ok()?;
+    if let WatchEvent::Key(key) = event {
+        return Some(WatchEvent::Key(key));
+    }
+
+    loop {
+        match events.recv_timeout(Duration::from_millis(100)) {
+            Ok(WatchEvent::InputChanged) => {}
+            Ok(WatchEvent::Key(key)) => return Some(WatchEvent::Key(key)),
+            Err(RecvTimeoutError::Timeout) => return Some(WatchEvent::InputChanged),
+            Err(RecvTimeoutError::Disconnected) => return None,
+        }
+    }
+}
+
+fn run_part(term: &Term, command: &dyn Command, part: usize) -> Result<()> {
+    if term.is_term() {
+        term.clear_screen()?;
+    }
+    println!(
+        "============={} {}, p to switch parts, q to quit=============",
+        command.get_name(),
+        PART_NAMES[part]
+    );
+
+    command.check_samples(part).into_iter().for_each(|check| {
+        let outcome = match &check.actual {
+            Ok(actual) if check.passed() => format!(
+                "{} {} in {:?}",
+                style("pass").green(),
+                actual,
+                check.elapsed
+            ),
+            Ok(actual) => format!(
+                "{} expected: {}, actual: {} in {:?}",
+                style("fail").red(),
+                check.expected,
+                actual,
+                check.elapsed
+            ),
+            Err(e) => format!("{} {}", style("error").red(), e),
+        };
+        println!("{:<20} {}", check.file, outcome);
+    });
+
+    let start = Instant::now();
+    let result = command.run_part(part);
+    let elapsed = start.elapsed();
+    match result {
+        Ok(answer) => println!("{:<20} {} in {:?}", "input.txt", answer, elapsed),
+        Err(e) => println!("{:<20} {} {}", "input.txt", style("error").red(), e),
+    }
+
+    Ok(())
+}